@@ -0,0 +1,197 @@
+use aoc_common::{parse_token, Answer, ParseError, Part};
+
+const TABLE_HEADER: &str = "[[answers]]";
+const KEY_SEPARATOR: &str = " = ";
+const STRING_QUOTE: char = '"';
+const ENTRY_FORM: &str = "`[[answers]]` or `<key> = <value>`";
+const KEY_FORM: &str = "`day`, `input`, `part_one` or `part_two`";
+const VALUE_FORM: &str = "a number or a \"string\"";
+
+/// The known answers of a single day's puzzle input
+#[derive(Debug, Default, PartialEq)]
+pub struct KnownAnswers {
+    pub day: u8,
+    /// Path of the puzzle input, relative to the answers file
+    pub input: String,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl KnownAnswers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+/// Parses an answers file. The file is the subset of TOML made of `[[answers]]` tables
+/// holding integers and basic strings, e.g.
+///
+/// ```toml
+/// [[answers]]
+/// day = 1
+/// input = "day1/src/data1.txt"
+/// part_one = 24000
+/// part_two = 45000
+/// ```
+///
+/// A part without an answer is reported as missing when verified.
+pub fn parse_answers(data: &str) -> Result<Vec<KnownAnswers>, ParseError> {
+    let mut answers = vec![];
+    // the current table along with the index and text of its header line
+    let mut table: Option<(usize, &str, KnownAnswers)> = None;
+
+    for (line_index, line) in data.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if trimmed == TABLE_HEADER {
+            if let Some(table) = table.replace((line_index, line, KnownAnswers::default())) {
+                answers.push(check_table(table)?);
+            }
+            continue;
+        }
+
+        let (key, value) = match trimmed.split_once(KEY_SEPARATOR) {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => return Err(ParseError::new(line_index, line, trimmed, ENTRY_FORM)),
+        };
+        let known_answers = match &mut table {
+            Some((_, _, known_answers)) => known_answers,
+            None => return Err(ParseError::new(line_index, line, key, TABLE_HEADER)),
+        };
+
+        match key {
+            "day" => known_answers.day = parse_token(line_index, line, value, "a day number")?,
+            "input" => known_answers.input = parse_string(line_index, line, value)?,
+            "part_one" => known_answers.part_one = Some(parse_answer(line_index, line, value)?),
+            "part_two" => known_answers.part_two = Some(parse_answer(line_index, line, value)?),
+            _ => return Err(ParseError::new(line_index, line, key, KEY_FORM)),
+        }
+    }
+
+    if let Some(table) = table {
+        answers.push(check_table(table)?);
+    }
+
+    Ok(answers)
+}
+
+/// Checks the table has the keys every table needs
+fn check_table(
+    (line_index, line, known_answers): (usize, &str, KnownAnswers),
+) -> Result<KnownAnswers, ParseError> {
+    match known_answers.day == 0 || known_answers.input.is_empty() {
+        true => Err(ParseError::new(
+            line_index,
+            line,
+            line,
+            "a table with a `day` and an `input`",
+        )),
+        false => Ok(known_answers),
+    }
+}
+
+fn parse_answer(line_index: usize, line: &str, value: &str) -> Result<Answer, ParseError> {
+    match value.starts_with(STRING_QUOTE) {
+        true => parse_string(line_index, line, value).map(Answer::from),
+        false => parse_token::<i64>(line_index, line, value, VALUE_FORM).map(Answer::from),
+    }
+}
+
+/// Parses a basic string, supporting the `\n`, `\"` and `\\` escapes
+fn parse_string(line_index: usize, line: &str, value: &str) -> Result<String, ParseError> {
+    let contents = match value
+        .strip_prefix(STRING_QUOTE)
+        .and_then(|value| value.strip_suffix(STRING_QUOTE))
+    {
+        Some(contents) => contents,
+        None => return Err(ParseError::new(line_index, line, value, "a \"string\"")),
+    };
+
+    let mut string = String::new();
+    let mut chars = contents.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, 'n')) => string.push('\n'),
+                Some((_, '"')) => string.push('"'),
+                Some((_, '\\')) => string.push('\\'),
+                _ => {
+                    return Err(ParseError::new(
+                        line_index,
+                        line,
+                        &contents[i..],
+                        "`\\n`, `\\\"` or `\\\\`",
+                    ))
+                }
+            },
+            '"' => {
+                return Err(ParseError::new(
+                    line_index,
+                    line,
+                    &contents[i..i + 1],
+                    "`\\\"` within a string",
+                ))
+            }
+            _ => string.push(c),
+        }
+    }
+
+    Ok(string)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Answer;
+
+    use crate::answers::{parse_answers, KnownAnswers};
+
+    #[test]
+    fn test_parse_answers() {
+        let data = "# known answers\n\
+                    [[answers]]\n\
+                    day = 5\n\
+                    input = \"day5/src/example.txt\"\n\
+                    part_one = \"CMZ\"\n\
+                    \n\
+                    [[answers]]\n\
+                    day = 10\n\
+                    input = \"day10/src/data1.txt\"\n\
+                    part_one = 15880\n\
+                    part_two = \"#.\\n.#\"\n";
+
+        assert_eq!(
+            parse_answers(data),
+            Ok(vec![
+                KnownAnswers {
+                    day: 5,
+                    input: "day5/src/example.txt".to_string(),
+                    part_one: Some(Answer::from("CMZ")),
+                    part_two: None,
+                },
+                KnownAnswers {
+                    day: 10,
+                    input: "day10/src/data1.txt".to_string(),
+                    part_one: Some(Answer::from(15880)),
+                    part_two: Some(Answer::from("#.\n.#")),
+                },
+            ])
+        );
+
+        let error = parse_answers("[[answers]]\nday = 1\nanswer = 5").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (3, 1, "answer")
+        );
+
+        let error = parse_answers("[[answers]]\ninput = \"day1/src/data1.txt\"").unwrap_err();
+        assert_eq!(error.line, 1);
+        assert!(parse_answers("day = 1").is_err());
+        assert!(parse_answers("[[answers]]\ninput = \"unterminated").is_err());
+    }
+}
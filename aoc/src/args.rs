@@ -0,0 +1,226 @@
+use aoc_common::Part;
+
+/// Number of times the bench command solves every day when no other number is passed
+const DEFAULT_ITERATIONS: usize = 10;
+
+/// Arguments of the run command
+#[derive(Debug, Default, PartialEq)]
+pub struct RunArgs {
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub file_name: Option<String>,
+    pub all: bool,
+}
+
+impl RunArgs {
+    pub fn parse(args: &[String]) -> Result<RunArgs, String> {
+        let mut run_args = RunArgs::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => run_args.day = Some(parse_day(next_value(arg, &mut args)?)?),
+                "--part" => run_args.part = Some(parse_part(next_value(arg, &mut args)?)?),
+                "--input" => run_args.file_name = Some(next_value(arg, &mut args)?.to_string()),
+                "--all" => run_args.all = true,
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        match (run_args.all, run_args.day, &run_args.file_name) {
+            (true, Some(_), _) => Err("--all cannot be combined with --day".to_string()),
+            (true, _, Some(_)) => Err("--all cannot be combined with --input".to_string()),
+            (false, None, _) => Err("either --day or --all is required".to_string()),
+            _ => Ok(run_args),
+        }
+    }
+}
+
+/// Arguments of the verify command
+#[derive(Debug, Default, PartialEq)]
+pub struct VerifyArgs {
+    pub day: Option<u8>,
+    pub answers_file_name: Option<String>,
+}
+
+impl VerifyArgs {
+    pub fn parse(args: &[String]) -> Result<VerifyArgs, String> {
+        let mut verify_args = VerifyArgs::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => verify_args.day = Some(parse_day(next_value(arg, &mut args)?)?),
+                "--answers" => {
+                    verify_args.answers_file_name = Some(next_value(arg, &mut args)?.to_string())
+                }
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        Ok(verify_args)
+    }
+}
+
+/// Arguments of the bench command
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub day: Option<u8>,
+    pub file_name: Option<String>,
+    pub iterations: usize,
+    pub json_file_name: Option<String>,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs {
+            day: None,
+            file_name: None,
+            iterations: DEFAULT_ITERATIONS,
+            json_file_name: None,
+        }
+    }
+}
+
+impl BenchArgs {
+    pub fn parse(args: &[String]) -> Result<BenchArgs, String> {
+        let mut bench_args = BenchArgs::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => bench_args.day = Some(parse_day(next_value(arg, &mut args)?)?),
+                "--input" => bench_args.file_name = Some(next_value(arg, &mut args)?.to_string()),
+                "--iterations" => {
+                    bench_args.iterations = parse_iterations(next_value(arg, &mut args)?)?
+                }
+                "--json" => {
+                    bench_args.json_file_name = Some(next_value(arg, &mut args)?.to_string())
+                }
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        match (bench_args.day, &bench_args.file_name) {
+            (None, Some(_)) => Err("--input requires --day".to_string()),
+            _ => Ok(bench_args),
+        }
+    }
+}
+
+fn next_value<'a>(
+    flag: &str,
+    args: &mut impl Iterator<Item = &'a String>,
+) -> Result<&'a str, String> {
+    match args.next() {
+        Some(value) => Ok(value),
+        None => Err(format!("{} requires a value", flag)),
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "day must be a number from 1 to 25, found '{}'",
+            value
+        )),
+    }
+}
+
+fn parse_iterations(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(iterations) if iterations > 0 => Ok(iterations),
+        _ => Err(format!(
+            "iterations must be a number above 0, found '{}'",
+            value
+        )),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("part must be 1 or 2, found '{}'", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Part;
+
+    use crate::args::{BenchArgs, RunArgs, VerifyArgs};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_run_args_parse() {
+        assert_eq!(
+            RunArgs::parse(&args(&["--day", "7", "--part", "2", "--input", "-"])),
+            Ok(RunArgs {
+                day: Some(7),
+                part: Some(Part::Two),
+                file_name: Some("-".to_string()),
+                all: false,
+            })
+        );
+        assert_eq!(
+            RunArgs::parse(&args(&["--all"])),
+            Ok(RunArgs {
+                all: true,
+                ..RunArgs::default()
+            })
+        );
+
+        assert!(RunArgs::parse(&args(&[])).is_err());
+        assert!(RunArgs::parse(&args(&["--day"])).is_err());
+        assert!(RunArgs::parse(&args(&["--day", "26"])).is_err());
+        assert!(RunArgs::parse(&args(&["--day", "1", "--part", "3"])).is_err());
+        assert!(RunArgs::parse(&args(&["--all", "--day", "1"])).is_err());
+        assert!(RunArgs::parse(&args(&["--day", "1", "--verbose"])).is_err());
+    }
+
+    #[test]
+    fn test_verify_args_parse() {
+        assert_eq!(VerifyArgs::parse(&args(&[])), Ok(VerifyArgs::default()));
+        assert_eq!(
+            VerifyArgs::parse(&args(&["--day", "3", "--answers", "answers.toml"])),
+            Ok(VerifyArgs {
+                day: Some(3),
+                answers_file_name: Some("answers.toml".to_string()),
+            })
+        );
+
+        assert!(VerifyArgs::parse(&args(&["--answers"])).is_err());
+        assert!(VerifyArgs::parse(&args(&["--all"])).is_err());
+    }
+
+    #[test]
+    fn test_bench_args_parse() {
+        assert_eq!(BenchArgs::parse(&args(&[])), Ok(BenchArgs::default()));
+        assert_eq!(
+            BenchArgs::parse(&args(&[
+                "--day",
+                "8",
+                "--input",
+                "-",
+                "--iterations",
+                "50",
+                "--json",
+                "bench.json"
+            ])),
+            Ok(BenchArgs {
+                day: Some(8),
+                file_name: Some("-".to_string()),
+                iterations: 50,
+                json_file_name: Some("bench.json".to_string()),
+            })
+        );
+
+        assert!(BenchArgs::parse(&args(&["--iterations", "0"])).is_err());
+        assert!(BenchArgs::parse(&args(&["--input", "-"])).is_err());
+    }
+}
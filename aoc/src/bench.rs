@@ -0,0 +1,223 @@
+use aoc_common::{parse_input, ParseError, Solver};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::days::Day;
+
+/// The phases of solving a day that are timed separately
+pub const PHASES: [&str; 3] = ["parse", "part_one", "part_two"];
+
+/// Parses the passed data with the passed solver, solves both parts and returns how
+/// long every phase took in the order of `PHASES`
+pub fn time_phases<S: Solver>(data: &str) -> Result<[Duration; 3], ParseError> {
+    let start = Instant::now();
+    let input = parse_input::<S>(data)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_one(&input));
+    let part_one = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_two(&input));
+    let part_two = start.elapsed();
+
+    Ok([parse, part_one, part_two])
+}
+
+/// The spread of the timings of a single phase over every iteration
+#[derive(Debug, Eq, PartialEq)]
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Summary {
+    /// Summarizes the passed timings, of which there must be at least one
+    pub fn new(mut timings: Vec<Duration>) -> Summary {
+        timings.sort();
+        let middle = timings.len() / 2;
+        let median = match timings.len().is_multiple_of(2) {
+            true => (timings[middle - 1] + timings[middle]) / 2,
+            false => timings[middle],
+        };
+
+        Summary {
+            min: timings[0],
+            median,
+            max: timings[timings.len() - 1],
+        }
+    }
+}
+
+/// The timings of every phase of a single day
+pub struct DayBench {
+    pub day: u8,
+    pub file_name: String,
+    /// A summary for every phase in the order of `PHASES`
+    pub summaries: Vec<Summary>,
+}
+
+/// Solves the passed day's data the passed number of times
+pub fn bench_day(
+    day: &Day,
+    file_name: &str,
+    data: &str,
+    iterations: usize,
+) -> Result<DayBench, ParseError> {
+    let mut timings = vec![vec![]; PHASES.len()];
+    for _ in 0..iterations {
+        for (phase_timings, timing) in timings.iter_mut().zip((day.time_phases)(data)?) {
+            phase_timings.push(timing);
+        }
+    }
+
+    Ok(DayBench {
+        day: day.number,
+        file_name: file_name.to_string(),
+        summaries: timings.into_iter().map(Summary::new).collect(),
+    })
+}
+
+/// Renders the benches as a table of durations in readable units
+pub fn format_benches(benches: &[DayBench]) -> String {
+    let mut table = format!(
+        "{:<4} {:<9} {:>11} {:>11} {:>11}\n",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+    for bench in benches {
+        for (phase, summary) in PHASES.iter().zip(&bench.summaries) {
+            table.push_str(&format!(
+                "{:<4} {:<9} {:>11} {:>11} {:>11}\n",
+                bench.day,
+                phase,
+                format_duration(summary.min),
+                format_duration(summary.median),
+                format_duration(summary.max),
+            ));
+        }
+    }
+    table.pop();
+    table
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.2}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// Renders the benches as JSON with durations in nanoseconds, e.g.
+///
+/// ```json
+/// {
+///   "iterations": 10,
+///   "days": [
+///     {
+///       "day": 1,
+///       "input": "day1/src/data1.txt",
+///       "parse": {"min_ns": 51200, "median_ns": 52010, "max_ns": 60450},
+///       "part_one": {"min_ns": 1300, "median_ns": 1310, "max_ns": 1500},
+///       "part_two": {"min_ns": 9800, "median_ns": 9900, "max_ns": 10300}
+///     }
+///   ]
+/// }
+/// ```
+pub fn benches_to_json(benches: &[DayBench], iterations: usize) -> String {
+    let days: Vec<String> = benches
+        .iter()
+        .map(|bench| {
+            let mut fields = vec![
+                format!("\"day\": {}", bench.day),
+                format!("\"input\": {}", json_string(&bench.file_name)),
+            ];
+            for (phase, summary) in PHASES.iter().zip(&bench.summaries) {
+                fields.push(format!(
+                    "\"{}\": {{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                    phase,
+                    summary.min.as_nanos(),
+                    summary.median.as_nanos(),
+                    summary.max.as_nanos()
+                ));
+            }
+            format!("    {{\n      {}\n    }}", fields.join(",\n      "))
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"iterations\": {},\n  \"days\": [\n{}\n  ]\n}}\n",
+        iterations,
+        days.join(",\n")
+    )
+}
+
+fn json_string(text: &str) -> String {
+    let mut string = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            c if c.is_control() => string.push_str(&format!("\\u{:04x}", c as u32)),
+            c => string.push(c),
+        }
+    }
+    string.push('"');
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{benches_to_json, json_string, DayBench, Summary};
+
+    #[test]
+    fn test_summary_new() {
+        let timings = [5, 1, 4, 2].map(Duration::from_nanos).to_vec();
+        assert_eq!(
+            Summary::new(timings),
+            Summary {
+                min: Duration::from_nanos(1),
+                median: Duration::from_nanos(3),
+                max: Duration::from_nanos(5),
+            }
+        );
+
+        let timings = [7, 3, 9].map(Duration::from_nanos).to_vec();
+        assert_eq!(Summary::new(timings).median, Duration::from_nanos(7));
+    }
+
+    #[test]
+    fn test_benches_to_json() {
+        let summary = |nanos| Summary {
+            min: Duration::from_nanos(nanos),
+            median: Duration::from_nanos(nanos + 1),
+            max: Duration::from_nanos(nanos + 2),
+        };
+        let benches = [DayBench {
+            day: 6,
+            file_name: "day6/src/data1.txt".to_string(),
+            summaries: vec![summary(10), summary(20), summary(30)],
+        }];
+
+        assert_eq!(
+            benches_to_json(&benches, 3),
+            "{\n  \"iterations\": 3,\n  \"days\": [\n    {\n      \
+             \"day\": 6,\n      \
+             \"input\": \"day6/src/data1.txt\",\n      \
+             \"parse\": {\"min_ns\": 10, \"median_ns\": 11, \"max_ns\": 12},\n      \
+             \"part_one\": {\"min_ns\": 20, \"median_ns\": 21, \"max_ns\": 22},\n      \
+             \"part_two\": {\"min_ns\": 30, \"median_ns\": 31, \"max_ns\": 32}\n    \
+             }\n  ]\n}\n"
+        );
+        assert_eq!(json_string("C:\\in \"1\""), "\"C:\\\\in \\\"1\\\"\"");
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_common::{get_answers, Answer, ParseError, Part};
+use std::time::Duration;
+
+use crate::bench::time_phases;
+
+/// Parses the passed data and returns the answer of every passed part
+type GetAnswers = fn(&str, &[Part]) -> Result<Vec<(Part, Answer)>, ParseError>;
+
+/// Parses the passed data, solves both parts and returns how long every phase took
+type TimePhases = fn(&str) -> Result<[Duration; 3], ParseError>;
+
+/// A day that can be run by the aoc binary
+pub struct Day {
+    pub number: u8,
+    /// The day's own puzzle input, used when no other input is passed
+    pub file_name: &'static str,
+    pub get_answers: GetAnswers,
+    pub time_phases: TimePhases,
+}
+
+macro_rules! day {
+    ($number:literal, $krate:ident, $solver:ident) => {
+        Day {
+            number: $number,
+            file_name: $krate::FILE_NAME,
+            get_answers: get_answers::<$krate::$solver>,
+            time_phases: time_phases::<$krate::$solver>,
+        }
+    };
+}
+
+/// Every implemented day in order
+pub const DAYS: &[Day] = &[
+    day!(1, day1, Day1),
+    day!(2, day2, Day2),
+    day!(3, day3, Day3),
+    day!(4, day4, Day4),
+    day!(5, day5, Day5),
+    day!(6, day6, Day6),
+    day!(7, day7, Day7),
+    day!(8, day8, Day8),
+    day!(9, day9, Day9),
+    day!(10, day10, Day10),
+    day!(11, day11, Day11),
+    day!(12, day12, Day12),
+    day!(13, day13, Day13),
+    day!(14, day14, Day14),
+    day!(15, day15, Day15),
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,193 @@
+mod answers;
+mod args;
+mod bench;
+mod days;
+mod verify;
+
+use answers::parse_answers;
+use aoc_common::{read_input, report, Part};
+use args::{BenchArgs, RunArgs, VerifyArgs};
+use bench::{bench_day, benches_to_json, format_benches};
+use days::{get_day, Day, DAYS};
+use std::{env, fs, path::Path, process};
+use verify::{format_checks, verify, Status};
+
+/// The known answers checked by the verify command when no other file is passed
+const ANSWERS_FILE_NAME: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+const USAGE: &str = "Usage:
+    aoc run --day <DAY> [--part <PART>] [--input <FILE>]
+    aoc run --all [--part <PART>]
+    aoc verify [--day <DAY>] [--answers <FILE>]
+    aoc bench [--day <DAY> [--input <FILE>]] [--iterations <N>] [--json <FILE>]
+
+Options:
+    --day <DAY>        Day to run, verify or bench. Every day is verified and benched by default
+    --part <PART>      Part to run, 1 or 2. Both parts run by default
+    --input <FILE>     Puzzle input file or - to read stdin. Defaults to the day's own input
+    --all              Run every implemented day on its own input
+    --answers <FILE>   Known answers to verify against. Defaults to the workspace's answers.toml
+    --iterations <N>   Number of times to solve every day when benching. Defaults to 10
+    --json <FILE>      Also write the bench timings to the file as JSON";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => RunArgs::parse(&args[1..])
+            .map_err(Error::Usage)
+            .and_then(|run_args| run(&run_args)),
+        Some("verify") => VerifyArgs::parse(&args[1..])
+            .map_err(Error::Usage)
+            .and_then(|verify_args| run_verify(&verify_args)),
+        Some("bench") => BenchArgs::parse(&args[1..])
+            .map_err(Error::Usage)
+            .and_then(|bench_args| run_bench(&bench_args)),
+        Some(command) => Err(Error::Usage(format!("unknown command '{}'", command))),
+        None => Err(Error::Usage("a command is required".to_string())),
+    };
+
+    match result {
+        Ok(()) => {}
+        Err(Error::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+        Err(Error::Failed) => process::exit(1),
+    }
+}
+
+/// The ways a command can fail
+enum Error {
+    /// The command line was invalid
+    Usage(String),
+    /// A file could not be read, a puzzle input could not be parsed or an answer was
+    /// wrong. The reason has already been printed
+    Failed,
+}
+
+fn run(run_args: &RunArgs) -> Result<(), Error> {
+    let parts = match run_args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    if run_args.all {
+        // Keep running the remaining days when one fails so every diagnostic is shown
+        let mut result = Ok(());
+        for day in DAYS {
+            println!("Day {}", day.number);
+            if run_day(day, day.file_name, &parts).is_err() {
+                result = Err(Error::Failed);
+            }
+        }
+        return result;
+    }
+
+    let number = run_args.day.unwrap();
+    let day = match get_day(number) {
+        Some(day) => day,
+        None => return Err(Error::Usage(format!("day {} is not implemented", number))),
+    };
+    let file_name = match &run_args.file_name {
+        Some(file_name) => file_name,
+        None => day.file_name,
+    };
+    run_day(day, file_name, &parts)
+}
+
+/// Reports the answers of the passed day or prints a diagnostic if its input cannot be parsed
+fn run_day(day: &Day, file_name: &str, parts: &[Part]) -> Result<(), Error> {
+    let data = read_file(file_name)?;
+
+    match (day.get_answers)(&data, parts) {
+        Ok(answers) => {
+            for (part, answer) in answers {
+                report(part, answer);
+            }
+            Ok(())
+        }
+        Err(error) => {
+            eprintln!("{}", error.diagnostic(file_name, &data));
+            Err(Error::Failed)
+        }
+    }
+}
+
+/// Reads the passed file, or stdin for `-`, or prints why it cannot be read
+fn read_file(file_name: &str) -> Result<String, Error> {
+    read_input(file_name).map_err(|error| {
+        eprintln!("error: cannot read {}: {}", file_name, error);
+        Error::Failed
+    })
+}
+
+/// Checks the answers of every known puzzle input and prints the results as a table
+fn run_verify(verify_args: &VerifyArgs) -> Result<(), Error> {
+    let file_name = match &verify_args.answers_file_name {
+        Some(file_name) => file_name,
+        None => ANSWERS_FILE_NAME,
+    };
+    let data = read_file(file_name)?;
+    let known_answers = match parse_answers(&data) {
+        Ok(known_answers) => known_answers,
+        Err(error) => {
+            eprintln!("{}", error.diagnostic(file_name, &data));
+            return Err(Error::Failed);
+        }
+    };
+
+    // inputs are relative to the answers file
+    let base_dir = Path::new(file_name).parent().unwrap_or(Path::new(""));
+    let checks = verify(&known_answers, base_dir, verify_args.day);
+    println!("{}", format_checks(&checks));
+
+    match checks.iter().any(|check| check.status == Status::Fail) {
+        true => Err(Error::Failed),
+        false => Ok(()),
+    }
+}
+
+/// Times the parse, part one and part two phases of every passed day
+fn run_bench(bench_args: &BenchArgs) -> Result<(), Error> {
+    let days: Vec<(&Day, &str)> = match bench_args.day {
+        Some(number) => match get_day(number) {
+            Some(day) => vec![(
+                day,
+                bench_args.file_name.as_deref().unwrap_or(day.file_name),
+            )],
+            None => return Err(Error::Usage(format!("day {} is not implemented", number))),
+        },
+        None => DAYS.iter().map(|day| (day, day.file_name)).collect(),
+    };
+
+    let mut result = Ok(());
+    let mut benches = vec![];
+    for (day, file_name) in days {
+        let data = match read_file(file_name) {
+            Ok(data) => data,
+            Err(error) => {
+                result = Err(error);
+                continue;
+            }
+        };
+        match bench_day(day, file_name, &data, bench_args.iterations) {
+            Ok(bench) => benches.push(bench),
+            Err(error) => {
+                eprintln!("{}", error.diagnostic(file_name, &data));
+                result = Err(Error::Failed);
+            }
+        }
+    }
+
+    println!("{}", format_benches(&benches));
+    if let Some(json_file_name) = &bench_args.json_file_name {
+        let json = benches_to_json(&benches, bench_args.iterations);
+        if let Err(error) = fs::write(json_file_name, json) {
+            eprintln!("error: cannot write {}: {}", json_file_name, error);
+            return Err(Error::Failed);
+        }
+    }
+
+    result
+}
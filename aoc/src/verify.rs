@@ -0,0 +1,203 @@
+use aoc_common::{Answer, Part};
+use std::{fmt, fs, path::Path};
+
+use crate::{
+    answers::KnownAnswers,
+    days::{get_day, DAYS},
+};
+
+const PARTS: [Part; 2] = [Part::One, Part::Two];
+
+/// The outcome of checking a single part of a single puzzle input
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There is no known answer to check against
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// A row of the verify table
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub status: Status,
+    pub detail: String,
+}
+
+/// Solves every known puzzle input, along with every implemented day without a known
+/// input, and checks the answers. Input paths are relative to the passed directory.
+/// Only the passed day is checked if there is one.
+pub fn verify(known_answers: &[KnownAnswers], base_dir: &Path, day: Option<u8>) -> Vec<Check> {
+    let mut checks = vec![];
+    let wanted = |number: u8| day.is_none() || day == Some(number);
+
+    for known in known_answers.iter().filter(|known| wanted(known.day)) {
+        checks.extend(verify_input(known, base_dir));
+    }
+
+    for day in DAYS.iter().filter(|day| wanted(day.number)) {
+        if known_answers.iter().any(|known| known.day == day.number) {
+            continue;
+        }
+        for part in PARTS {
+            checks.push(Check {
+                day: day.number,
+                part,
+                input: "none".to_string(),
+                status: Status::Missing,
+                detail: "no input is registered".to_string(),
+            });
+        }
+    }
+
+    checks.sort_by_key(|check| check.day);
+    checks
+}
+
+fn verify_input(known: &KnownAnswers, base_dir: &Path) -> Vec<Check> {
+    let check = |part, status, detail| Check {
+        day: known.day,
+        part,
+        input: known.input.clone(),
+        status,
+        detail,
+    };
+    let fail_every_part =
+        |detail: String| Vec::from(PARTS.map(|part| check(part, Status::Fail, detail.clone())));
+
+    let day = match get_day(known.day) {
+        Some(day) => day,
+        None => return fail_every_part(format!("day {} is not implemented", known.day)),
+    };
+    let data = match fs::read_to_string(base_dir.join(&known.input)) {
+        Ok(data) => data,
+        Err(error) => return fail_every_part(format!("cannot read the input: {}", error)),
+    };
+    let answers = match (day.get_answers)(&data, &PARTS) {
+        Ok(answers) => answers,
+        Err(error) => return fail_every_part(format!("cannot parse the input: {}", error)),
+    };
+
+    answers
+        .into_iter()
+        .map(|(part, answer)| match known.get(part) {
+            Some(expected) if *expected == answer => check(part, Status::Pass, summarize(&answer)),
+            Some(expected) => check(
+                part,
+                Status::Fail,
+                format!(
+                    "expected {}, found {}",
+                    summarize(expected),
+                    summarize(&answer)
+                ),
+            ),
+            None => check(
+                part,
+                Status::Missing,
+                format!("found {}", summarize(&answer)),
+            ),
+        })
+        .collect()
+}
+
+/// Shortens multi-line answers, such as day10's image, to their first line
+fn summarize(answer: &Answer) -> String {
+    let answer = answer.to_string();
+    match answer.split_once('\n') {
+        Some((first_line, _)) => format!("{}...", first_line),
+        None => answer,
+    }
+}
+
+/// Renders the checks as a table followed by the number of checks with every status
+pub fn format_checks(checks: &[Check]) -> String {
+    let header = ["Day", "Part", "Input", "Result", "Answer"];
+    let mut rows = vec![header.map(String::from)];
+    for check in checks {
+        rows.push([
+            check.day.to_string(),
+            match check.part {
+                Part::One => "1".to_string(),
+                Part::Two => "2".to_string(),
+            },
+            check.input.clone(),
+            check.status.to_string(),
+            check.detail.clone(),
+        ]);
+    }
+
+    let mut widths = [0; 5];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in &rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+
+    let count = |status| checks.iter().filter(|check| check.status == status).count();
+    table.push_str(&format!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing)
+    ));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Answer, Part};
+    use std::path::Path;
+
+    use crate::{
+        answers::KnownAnswers,
+        verify::{verify, Status},
+    };
+
+    #[test]
+    fn test_verify() {
+        let known_answers = [KnownAnswers {
+            day: 2,
+            input: "day2/src/example.txt".to_string(),
+            part_one: Some(Answer::from(15)),
+            part_two: Some(Answer::from(13)),
+        }];
+        let base_dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+
+        let checks = verify(&known_answers, base_dir, Some(2));
+        let statuses: Vec<(Part, Status)> = checks
+            .iter()
+            .map(|check| (check.part, check.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            [(Part::One, Status::Pass), (Part::Two, Status::Fail)]
+        );
+        assert_eq!(checks[1].detail, "expected 13, found 12");
+
+        let checks = verify(&[], base_dir, Some(2));
+        assert!(checks.iter().all(|check| check.status == Status::Missing));
+    }
+}
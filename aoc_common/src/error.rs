@@ -0,0 +1,157 @@
+use std::{error::Error, fmt};
+
+/// Describes where and why a day's puzzle input could not be parsed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// Line number of the offending text, starting from 1
+    pub line: usize,
+    /// Column number of the offending text, starting from 1
+    pub column: usize,
+    /// The offending text. Empty when the text ended before the expected form.
+    pub text: String,
+    /// The form the parser expected to find instead of the offending text
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for the offending text found on the line at the passed index.
+    /// The column is found from the position of the text within the line so the text
+    /// should be a slice of the line, such as a token split from it.
+    pub fn new(line_index: usize, line: &str, text: &str, expected: &str) -> ParseError {
+        let line_start = line.as_ptr() as usize;
+        let text_start = text.as_ptr() as usize;
+
+        let offset =
+            if text_start >= line_start && text_start + text.len() <= line_start + line.len() {
+                text_start - line_start
+            } else {
+                line.find(text).unwrap_or(0)
+            };
+
+        ParseError {
+            line: line_index + 1,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Creates an error for a line at the passed index that ended before the expected
+    /// form was found. A missing line is treated as an empty line.
+    pub fn end_of_line(line_index: usize, line: &str, expected: &str) -> ParseError {
+        ParseError {
+            line: line_index + 1,
+            column: line.chars().count() + 1,
+            text: String::new(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Renders the error along with the offending line of the passed data, e.g.
+    ///
+    /// ```text
+    /// error: expected `addx <value>` or `noop`, found `add`
+    ///  --> data1.txt:3:1
+    ///   |
+    /// 3 | add 5
+    ///   | ^^^
+    /// ```
+    pub fn diagnostic(&self, file_name: &str, data: &str) -> String {
+        let source_line = data.lines().nth(self.line - 1).unwrap_or("");
+        let gutter = " ".repeat(self.line.to_string().len());
+        let marker = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.message(),
+            gutter,
+            file_name,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            source_line,
+            gutter,
+            " ".repeat(self.column - 1),
+            marker,
+        )
+    }
+
+    fn message(&self) -> String {
+        match self.text.is_empty() {
+            true => format!("expected {}, found nothing", self.expected),
+            false => format!("expected {}, found `{}`", self.expected, self.text),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a token of the line at the passed index, such as a number, or returns
+/// an error pointing at the token.
+pub fn parse_token<T: std::str::FromStr>(
+    line_index: usize,
+    line: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(line_index, line, token, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_token, ParseError};
+
+    #[test]
+    fn test_parse_error_new() {
+        let line = "move 1 from x to 3";
+        let token = line.split(' ').nth(3).unwrap();
+        let error = ParseError::new(4, line, token, "a stack number");
+
+        assert_eq!(error.line, 5);
+        assert_eq!(error.column, 13);
+        assert_eq!(error.text, "x");
+
+        let error = ParseError::new(0, line, "to", "a stack number");
+        assert_eq!(error.column, 15);
+
+        let error = ParseError::end_of_line(0, "addx", "a value");
+        assert_eq!(error.column, 5);
+        assert_eq!(error.text, "");
+    }
+
+    #[test]
+    fn test_parse_error_diagnostic() {
+        let data = "noop\nadd 5\nnoop";
+        let line = data.lines().nth(1).unwrap();
+        let error = ParseError::new(1, line, &line[..3], "`addx <value>` or `noop`");
+
+        assert_eq!(
+            error.diagnostic("data1.txt", data),
+            "error: expected `addx <value>` or `noop`, found `add`\n \
+             --> data1.txt:2:1\n  \
+             |\n\
+             2 | add 5\n  \
+             | ^^^"
+        );
+    }
+
+    #[test]
+    fn test_parse_token() {
+        assert_eq!(parse_token::<i32>(0, "x=-2", "-2", "a number"), Ok(-2));
+        assert_eq!(
+            parse_token::<i32>(0, "x=a", "a", "a number")
+                .unwrap_err()
+                .column,
+            3
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Points on a 2D grid along with the directions and distances between them.
+//! y grows downwards, as in the rows of a `Grid`, so moving `U` decreases y.
+
+use std::{
+    fmt,
+    hash::Hash,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+/// A signed integer that can be used as a coordinate
+pub trait Coordinate:
+    Copy + fmt::Debug + Hash + Ord + Add<Output = Self> + Sub<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($coordinate:ty),*) => {
+        $(
+            impl Coordinate for $coordinate {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$coordinate>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// A point holding its x and y coordinates
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T = i32>(pub T, pub T);
+
+impl<T: Coordinate> Point<T> {
+    /// The point one step away in the passed direction
+    pub fn step(self, direction: Direction) -> Point<T> {
+        self + direction.offset()
+    }
+
+    /// The point one step away in the passed direction, which may be diagonal
+    pub fn step8(self, direction: Direction8) -> Point<T> {
+        self + direction.offset()
+    }
+
+    /// The four points sharing an edge with this point, in the order of `Direction::ALL`
+    pub fn neighbours(self) -> [Point<T>; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// The eight points sharing an edge or a corner with this point, in the order of
+    /// `Direction8::ALL`
+    pub fn neighbours8(self) -> [Point<T>; 8] {
+        Direction8::ALL.map(|direction| self.step8(direction))
+    }
+
+    /// Number of steps between the points when only moving up, down, left or right
+    pub fn manhattan_distance(self, other: Point<T>) -> T {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs()
+    }
+
+    /// Number of steps between the points when diagonal steps are allowed too
+    pub fn chebyshev_distance(self, other: Point<T>) -> T {
+        (self.0 - other.0).abs().max((self.1 - other.1).abs())
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+/// Up, down, right or left
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    U,
+    D,
+    R,
+    L,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::U, Direction::D, Direction::R, Direction::L];
+
+    /// The difference between a point and the point one step away in this direction
+    pub fn offset<T: Coordinate>(self) -> Point<T> {
+        Direction8::from(self).offset()
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::U => Direction::D,
+            Direction::D => Direction::U,
+            Direction::R => Direction::L,
+            Direction::L => Direction::R,
+        }
+    }
+}
+
+/// Parses the `U`, `D`, `R` and `L` letters
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(letter: &str) -> Result<Self, Self::Err> {
+        match letter {
+            "U" => Ok(Direction::U),
+            "D" => Ok(Direction::D),
+            "R" => Ok(Direction::R),
+            "L" => Ok(Direction::L),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Up, down, right, left or one of the four diagonals between them
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction8 {
+    U,
+    D,
+    R,
+    L,
+    UR,
+    UL,
+    DR,
+    DL,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::U,
+        Direction8::D,
+        Direction8::R,
+        Direction8::L,
+        Direction8::UR,
+        Direction8::UL,
+        Direction8::DR,
+        Direction8::DL,
+    ];
+    pub const DIAGONALS: [Direction8; 4] = [
+        Direction8::UR,
+        Direction8::UL,
+        Direction8::DR,
+        Direction8::DL,
+    ];
+
+    /// The difference between a point and the point one step away in this direction
+    pub fn offset<T: Coordinate>(self) -> Point<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        let minus_one = zero - one;
+        match self {
+            Direction8::U => Point(zero, minus_one),
+            Direction8::D => Point(zero, one),
+            Direction8::R => Point(one, zero),
+            Direction8::L => Point(minus_one, zero),
+            Direction8::UR => Point(one, minus_one),
+            Direction8::UL => Point(minus_one, minus_one),
+            Direction8::DR => Point(one, one),
+            Direction8::DL => Point(minus_one, one),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::U => Direction8::U,
+            Direction::D => Direction8::D,
+            Direction::R => Direction8::R,
+            Direction::L => Direction8::L,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Direction, Direction8, Point};
+
+    #[test]
+    fn test_point_step() {
+        assert_eq!(Point(0, 0).step(Direction::U), Point(0, -1));
+        assert_eq!(Point(0, 0).step(Direction::L), Point(-1, 0));
+        assert_eq!(Point(2_i64, 3).step8(Direction8::DR), Point(3, 4));
+        assert_eq!(
+            Point(0, 0).neighbours(),
+            [Point(0, -1), Point(0, 1), Point(1, 0), Point(-1, 0)]
+        );
+        assert_eq!(Point(5, 5).neighbours8().len(), 8);
+        assert!(Point(5, 5)
+            .neighbours8()
+            .iter()
+            .all(|point| point.chebyshev_distance(Point(5, 5)) == 1));
+    }
+
+    #[test]
+    fn test_point_distances() {
+        assert_eq!(Point(0, 0).manhattan_distance(Point(0, 0)), 0);
+        assert_eq!(Point(1, -2).manhattan_distance(Point(-2, 2)), 7);
+        assert_eq!(Point(-2, 2).manhattan_distance(Point(1, -2)), 7);
+
+        assert_eq!(Point(0, 0).chebyshev_distance(Point(0, 0)), 0);
+        assert_eq!(Point(1, -2).chebyshev_distance(Point(-2, 2)), 4);
+        assert_eq!(Point(1, 1).chebyshev_distance(Point(0, 0)), 1);
+    }
+
+    #[test]
+    fn test_direction_from_str() {
+        assert_eq!("U".parse(), Ok(Direction::U));
+        assert_eq!("L".parse(), Ok(Direction::L));
+        assert_eq!("X".parse::<Direction>(), Err(()));
+        assert_eq!("UL".parse::<Direction>(), Err(()));
+    }
+}
@@ -0,0 +1,313 @@
+//! A dense rectangle of cells indexed by point. y grows downwards so the first line of
+//! a parsed grid is the row with the lowest y.
+
+use std::{
+    fmt, iter,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Direction, Point},
+    ParseError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    /// Every cell, row by row
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    /// Point of the first cell of the first row
+    origin: Point,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid covering every point from the passed minimum point to the passed
+    /// maximum point inclusive, with every cell set to the passed value
+    pub fn from_bounds(min: Point, max: Point, value: T) -> Grid<T> {
+        let width = (max.0 - min.0 + 1).max(0) as usize;
+        let height = (max.1 - min.1 + 1).max(0) as usize;
+
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+            origin: min,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses every line of the passed data as a row of cells, starting from `Point(0, 0)`.
+    /// Every row must be as long as the first row. The cell form describes the characters
+    /// the passed function can parse.
+    pub fn parse(
+        data: &str,
+        cell_form: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (line_index, line) in data.lines().enumerate() {
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                match parse_cell(c) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        return Err(ParseError::new(
+                            line_index,
+                            line,
+                            &line[i..i + c.len_utf8()],
+                            cell_form,
+                        ))
+                    }
+                }
+                row_width += 1;
+            }
+
+            // every row must have as many cells as the first row
+            let width = *width.get_or_insert(row_width);
+            let expected = format!("a row of {} cells", width);
+            if row_width < width {
+                return Err(ParseError::end_of_line(line_index, line, &expected));
+            } else if row_width > width {
+                let (extra_start, _) = line.char_indices().nth(width).unwrap();
+                return Err(ParseError::new(
+                    line_index,
+                    line,
+                    &line[extra_start..],
+                    &expected,
+                ));
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                cells,
+                width,
+                height,
+                origin: Point(0, 0),
+            }),
+            _ => Err(ParseError::end_of_line(0, "", "a row of cells")),
+        }
+    }
+
+    /// Creates a grid of the same size holding the result of the passed function for
+    /// every cell
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            origin: self.origin,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The lowest point in the grid
+    pub fn min(&self) -> Point {
+        self.origin
+    }
+
+    /// The highest point in the grid
+    pub fn max(&self) -> Point {
+        Point(
+            self.origin.0 + self.width as i32 - 1,
+            self.origin.1 + self.height as i32 - 1,
+        )
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_of(i))
+    }
+
+    /// Every point in the grid along with its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    /// Every row of cells from the lowest y to the highest. A grid with no columns has
+    /// no cells, so it has no rows either.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of the row at the passed y, from the lowest x to the highest
+    pub fn row(&self, y: i32) -> Option<&[T]> {
+        let start = self.index_of(Point(self.origin.0, y))?;
+        Some(&self.cells[start..start + self.width])
+    }
+
+    /// The cells of the column at the passed x, from the lowest y to the highest
+    pub fn column(&self, x: i32) -> Option<impl Iterator<Item = &T>> {
+        let start = self.index_of(Point(x, self.origin.1))?;
+        Some(self.cells[start..].iter().step_by(self.width))
+    }
+
+    /// Walks from the passed point in the passed direction until the edge of the grid,
+    /// yielding every point after the passed point along with its cell
+    pub fn ray(&self, start: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        let offset = direction.offset();
+        iter::successors(Some(start + offset), move |point| Some(*point + offset))
+            .map_while(move |point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.0 - self.origin.0).ok()?;
+        let y = usize::try_from(point.1 - self.origin.1).ok()?;
+
+        match x < self.width && y < self.height {
+            true => Some(y * self.width + x),
+            false => None,
+        }
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point(
+            self.origin.0 + (index % self.width) as i32,
+            self.origin.1 + (index / self.width) as i32,
+        )
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{} is outside the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{} is outside the grid", point),
+        }
+    }
+}
+
+/// Renders every row on its own line
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{Direction, Point},
+        grid::Grid,
+    };
+
+    fn digits(data: &str) -> Grid<u32> {
+        Grid::parse(data, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_grid_parse() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point(0, 0)], 1);
+        assert_eq!(grid[Point(2, 1)], 6);
+        assert_eq!(grid.get(Point(3, 0)), None);
+        assert_eq!(grid.get(Point(0, -1)), None);
+        assert_eq!(grid.map(|digit| digit * 2)[Point(2, 1)], 12);
+
+        let error = Grid::parse("12\n1x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = Grid::parse("12\n1", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = Grid::parse("12\n123", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "3"));
+        assert!(Grid::parse("", "a digit", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_grid_from_bounds() {
+        let mut grid = Grid::from_bounds(Point(-2, 3), Point(1, 4), '.');
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert_eq!((grid.min(), grid.max()), (Point(-2, 3), Point(1, 4)));
+
+        grid[Point(-2, 3)] = '#';
+        grid[Point(1, 4)] = 'o';
+        assert!(grid.contains(Point(0, 4)));
+        assert!(!grid.contains(Point(2, 4)));
+        assert_eq!(grid.to_string(), "#...\n...o");
+        assert_eq!(grid.points().last(), Some(Point(1, 4)));
+
+        let grid = Grid::from_bounds(Point(1, 0), Point(0, 2), '.');
+        assert_eq!((grid.width(), grid.height()), (0, 3));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.column(0).map(|column| column.count()), None);
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn test_grid_rows_and_columns() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(grid.rows().nth(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), Some(&[7, 8, 9][..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(
+            grid.column(1).unwrap().copied().collect::<Vec<_>>(),
+            [2, 5, 8]
+        );
+        assert!(grid.column(-1).is_none());
+    }
+
+    #[test]
+    fn test_grid_ray() {
+        let grid = digits("123\n456\n789");
+        let ray = |start, direction| {
+            grid.ray(start, direction)
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ray(Point(0, 1), Direction::R), [5, 6]);
+        assert_eq!(ray(Point(1, 1), Direction::L), [4]);
+        assert_eq!(ray(Point(1, 0), Direction::D), [5, 8]);
+        assert_eq!(ray(Point(1, 2), Direction::U), [5, 2]);
+        assert_eq!(ray(Point(1, 0), Direction::U), []);
+    }
+}
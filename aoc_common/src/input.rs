@@ -0,0 +1,69 @@
+use std::{
+    fs,
+    io::{self, Read},
+    process,
+};
+
+/// Passing this file name reads the puzzle input from stdin instead of a file
+pub const STDIN_FILE_NAME: &str = "-";
+
+/// The path of a puzzle input file in the `src` directory of the crate using the macro,
+/// `data1.txt` unless another file is passed. The path is resolved when the crate is
+/// compiled so the file is found from any working directory.
+#[macro_export]
+macro_rules! input_file {
+    () => {
+        $crate::input_file!("data1.txt")
+    };
+    ($file_name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/src/", $file_name)
+    };
+}
+
+/// Reads the puzzle input stored at the passed file name as is. It is normalized
+/// when it is parsed.
+pub fn read_input(file_name: &str) -> io::Result<String> {
+    match file_name == STDIN_FILE_NAME {
+        true => {
+            let mut data = String::new();
+            io::stdin().read_to_string(&mut data)?;
+            Ok(data)
+        }
+        false => fs::read_to_string(file_name),
+    }
+}
+
+/// Reads the puzzle input stored at the passed file name. Exits with an error if it
+/// cannot be read.
+pub fn read_input_or_exit(file_name: &str) -> String {
+    match read_input(file_name) {
+        Ok(data) => data,
+        Err(error) => {
+            eprintln!("error: cannot read {}: {}", file_name, error);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use crate::read_input;
+
+    #[test]
+    fn test_input_file() {
+        assert!(input_file!().ends_with("aoc_common/src/data1.txt"));
+        assert!(input_file!("example.txt").ends_with("aoc_common/src/example.txt"));
+    }
+
+    #[test]
+    fn test_read_input() {
+        let data = read_input(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")).unwrap();
+        assert!(data.contains("aoc_common"));
+        assert_eq!(
+            read_input("/nonexistent/input.txt").unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! Ranges of whole numbers that take constant space however wide they are, along with
+//! sets of them kept sorted and merged.
+
+use std::fmt;
+
+use crate::geometry::Coordinate;
+
+/// Every whole number from the start to the end inclusive. The start is never after
+/// the end so an interval is never empty.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Interval<T = i32> {
+    start: T,
+    end: T,
+}
+
+impl<T: Coordinate> Interval<T> {
+    /// The interval from the passed start to the passed end inclusive, if the start is
+    /// not after the end
+    pub fn new(start: T, end: T) -> Option<Interval<T>> {
+        match start <= end {
+            true => Some(Interval { start, end }),
+            false => None,
+        }
+    }
+
+    pub fn start(self) -> T {
+        self.start
+    }
+
+    pub fn end(self) -> T {
+        self.end
+    }
+
+    /// Number of whole numbers in the interval
+    pub fn len(self) -> T {
+        self.end - self.start + T::ONE
+    }
+
+    pub fn contains(self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every number in this interval is also in the passed interval
+    pub fn is_subset(self, other: Interval<T>) -> bool {
+        other.start <= self.start && self.end <= other.end
+    }
+
+    /// Whether any number is in both intervals
+    pub fn overlaps(self, other: Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// The numbers in both intervals, if there are any
+    pub fn intersection(self, other: Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The numbers in either interval, if they overlap or touch so that the numbers
+    /// form a single interval
+    pub fn union(self, other: Interval<T>) -> Option<Interval<T>> {
+        match self.start <= other.end + T::ONE && other.start <= self.end + T::ONE {
+            true => Interval::new(self.start.min(other.start), self.end.max(other.end)),
+            false => None,
+        }
+    }
+}
+
+/// Renders the interval as `<start>-<end>`
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of whole numbers stored as intervals sorted by their start that neither
+/// overlap nor touch
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalSet<T = i32> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Coordinate> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// Adds every number in the passed interval, merging it with every interval it
+    /// overlaps or touches
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // the intervals that end before the passed interval and do not touch it
+        let before = self
+            .intervals
+            .partition_point(|other| other.end + T::ONE < interval.start);
+        let mut merged = interval;
+        let mut after = before;
+
+        while let Some(union) = self
+            .intervals
+            .get(after)
+            .and_then(|other| other.union(merged))
+        {
+            merged = union;
+            after += 1;
+        }
+
+        self.intervals.splice(before..after, [merged]);
+    }
+
+    /// The intervals of the set, sorted by their start
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Number of whole numbers in the set
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |len, interval| len + interval.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+        match self.intervals.get(i) {
+            Some(interval) => interval.contains(value),
+            None => false,
+        }
+    }
+
+    /// The numbers between the lowest and the highest number in the set that are not in it
+    pub fn gaps(&self) -> IntervalSet<T> {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .windows(2)
+                .filter_map(|pair| Interval::new(pair[0].end + T::ONE, pair[1].start - T::ONE))
+                .collect(),
+        }
+    }
+
+    /// The numbers in either set
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    /// The numbers in both sets
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(first), Some(second)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(first.intersection(*second));
+            // the interval that ends first cannot overlap any later interval of the other set
+            match first.end <= second.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Coordinate> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::{Interval, IntervalSet};
+
+    fn interval(start: i64, end: i64) -> Interval<i64> {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_interval() {
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(interval(2, 2).len(), 1);
+        assert_eq!(interval(1, 4_000_000_000).len(), 4_000_000_000);
+        assert!(interval(2, 4).contains(4));
+        assert!(!interval(2, 4).contains(5));
+
+        assert!(interval(3, 4).is_subset(interval(2, 4)));
+        assert!(!interval(2, 4).is_subset(interval(3, 4)));
+        assert!(interval(2, 4).overlaps(interval(4, 6)));
+        assert!(!interval(2, 4).overlaps(interval(5, 6)));
+
+        assert_eq!(
+            interval(2, 6).intersection(interval(4, 8)),
+            Some(interval(4, 6))
+        );
+        assert_eq!(interval(2, 3).intersection(interval(4, 8)), None);
+        assert_eq!(interval(2, 3).union(interval(4, 8)), Some(interval(2, 8)));
+        assert_eq!(interval(5, 9).union(interval(1, 6)), Some(interval(1, 9)));
+        assert_eq!(interval(2, 3).union(interval(5, 8)), None);
+        assert_eq!(interval(-2, 3).to_string(), "-2-3");
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let mut set: IntervalSet<i64> = [interval(10, 12), interval(1, 2), interval(5, 6)]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            set.intervals(),
+            [interval(1, 2), interval(5, 6), interval(10, 12)]
+        );
+        assert_eq!(set.len(), 7);
+
+        set.insert(interval(3, 7));
+        assert_eq!(set.intervals(), [interval(1, 7), interval(10, 12)]);
+        set.insert(interval(9, 9));
+        assert_eq!(set.intervals(), [interval(1, 7), interval(9, 12)]);
+        assert!(set.contains(9));
+        assert!(!set.contains(8));
+        assert!(!set.contains(13));
+        assert!(IntervalSet::<i64>::new().is_empty());
+
+        assert_eq!(set.gaps().intervals(), [interval(8, 8)]);
+        set.insert(interval(20, 30));
+        assert_eq!(set.gaps().intervals(), [interval(8, 8), interval(13, 19)]);
+        assert!(IntervalSet::<i64>::new().gaps().is_empty());
+    }
+
+    #[test]
+    fn test_interval_set_algebra() {
+        let first: IntervalSet<i64> = [interval(1, 4), interval(8, 10)].into_iter().collect();
+        let second: IntervalSet<i64> = [interval(3, 8), interval(12, 12)].into_iter().collect();
+
+        assert_eq!(
+            first.union(&second).intervals(),
+            [interval(1, 10), interval(12, 12)]
+        );
+        assert_eq!(
+            first.intersection(&second).intervals(),
+            [interval(3, 4), interval(8, 8)]
+        );
+        assert!(first.intersection(&IntervalSet::new()).is_empty());
+    }
+}
@@ -0,0 +1,20 @@
+//! Code shared by every day's solution such as reading the puzzle input,
+//! reporting parse errors, solving both parts and reporting answers.
+
+mod answer;
+mod error;
+pub mod geometry;
+pub mod grid;
+mod input;
+pub mod interval;
+mod normalize;
+mod report;
+pub mod search;
+mod solver;
+
+pub use answer::Answer;
+pub use error::{parse_token, ParseError};
+pub use input::{read_input, read_input_or_exit, STDIN_FILE_NAME};
+pub use normalize::{BlankLines, NormalizedInput};
+pub use report::{report, Part};
+pub use solver::{get_answers, parse_input, solve, Solver};
@@ -0,0 +1,112 @@
+use crate::ParseError;
+
+/// How a day's puzzle input uses blank lines
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlankLines {
+    /// Blank lines carry no meaning and are removed
+    Ignored,
+    /// Blank lines separate groups of lines such as day1's elves. Runs of blank lines
+    /// are collapsed into a single blank line.
+    Separator,
+}
+
+/// Puzzle input with LF line endings, no trailing whitespace on any line and no
+/// leading or trailing blank lines. The line of the original input every normalized
+/// line came from is kept so parse errors still point into the original input.
+#[derive(Debug, Eq, PartialEq)]
+pub struct NormalizedInput {
+    pub text: String,
+    /// Original line number, starting from 1, of every normalized line
+    line_numbers: Vec<usize>,
+}
+
+impl NormalizedInput {
+    pub fn new(data: &str, blank_lines: BlankLines) -> NormalizedInput {
+        let mut lines: Vec<(usize, &str)> = Vec::new();
+
+        for (line_index, line) in data.lines().enumerate() {
+            let line = line.trim_end();
+            let previous_is_blank = match lines.last() {
+                Some((_, previous)) => previous.is_empty(),
+                None => true,
+            };
+
+            if line.is_empty() && (blank_lines == BlankLines::Ignored || previous_is_blank) {
+                continue;
+            }
+            lines.push((line_index + 1, line));
+        }
+
+        if let Some((_, "")) = lines.last() {
+            lines.pop();
+        }
+
+        NormalizedInput {
+            text: lines
+                .iter()
+                .map(|(_, line)| *line)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            line_numbers: lines.iter().map(|(line_number, _)| *line_number).collect(),
+        }
+    }
+
+    /// Line of the original input, starting from 1, that the normalized line at the
+    /// passed index came from
+    pub fn line_number(&self, line_index: usize) -> Option<usize> {
+        self.line_numbers.get(line_index).copied()
+    }
+
+    /// Moves the passed error, found in the normalized text, to the matching line of
+    /// the original input. Errors past the last line are moved past the last line of
+    /// the original input.
+    pub fn locate(&self, mut error: ParseError) -> ParseError {
+        error.line = match self.line_number(error.line - 1) {
+            Some(line_number) => line_number,
+            None => {
+                self.line_numbers.last().copied().unwrap_or(0) + error.line
+                    - self.line_numbers.len()
+            }
+        };
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlankLines, NormalizedInput, ParseError};
+
+    #[test]
+    fn test_normalized_input_new() {
+        let data = "\r\n1000 \r\n2000\r\n\r\n\r\n3000\t\r\n\r\n";
+
+        assert_eq!(
+            NormalizedInput::new(data, BlankLines::Separator).text,
+            "1000\n2000\n\n3000"
+        );
+        assert_eq!(
+            NormalizedInput::new(data, BlankLines::Ignored).text,
+            "1000\n2000\n3000"
+        );
+        assert_eq!(NormalizedInput::new("R 4", BlankLines::Ignored).text, "R 4");
+        assert_eq!(NormalizedInput::new("\n\n", BlankLines::Separator).text, "");
+
+        let normalized = NormalizedInput::new(data, BlankLines::Ignored);
+        assert_eq!(normalized.line_number(2), Some(6));
+        assert_eq!(normalized.line_number(3), None);
+    }
+
+    #[test]
+    fn test_normalized_input_locate() {
+        let data = "\nnoop\n\nadd 5\n";
+        let input = NormalizedInput::new(data, BlankLines::Ignored);
+        let line = input.text.lines().nth(1).unwrap();
+
+        let error = input.locate(ParseError::new(1, line, &line[..3], "`noop`"));
+        assert_eq!(error.line, 4);
+        assert_eq!(error.column, 1);
+
+        let error = input.locate(ParseError::end_of_line(2, "", "`noop`"));
+        assert_eq!(error.line, 5);
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part one"),
+            Part::Two => write!(f, "Part two"),
+        }
+    }
+}
+
+/// Prints the answer of a part. Answers spanning several lines, such as
+/// rendered images, start on the line below the part name.
+pub fn report(part: Part, answer: impl fmt::Display) {
+    let answer = answer.to_string();
+    match answer.contains('\n') {
+        true => println!("{}:\n{}", part, answer),
+        false => println!("{}: {}", part, answer),
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::VecDeque;
+
+use crate::{geometry::Point, grid::Grid};
+
+/// The shortest paths found by a breadth first search over a grid
+pub struct Paths {
+    /// Fewest steps from any start to every reached point
+    steps: Grid<Option<usize>>,
+    /// The point every reached point was first reached from. None for the starts.
+    previous: Grid<Option<Point>>,
+}
+
+impl Paths {
+    /// Fewest steps from any start to the passed point, if it was reached
+    pub fn steps(&self, point: Point) -> Option<usize> {
+        self.steps.get(point).copied().flatten()
+    }
+
+    /// Every reached point along with the fewest steps to reach it
+    pub fn reached(&self) -> impl Iterator<Item = (Point, usize)> + '_ {
+        self.steps
+            .iter()
+            .filter_map(|(point, steps)| steps.map(|steps| (point, steps)))
+    }
+
+    /// A shortest path from a start to the passed point, including both, if it was reached
+    pub fn path(&self, point: Point) -> Option<Vec<Point>> {
+        self.steps(point)?;
+
+        let mut path = vec![point];
+        while let Some(previous) = self.previous[path[path.len() - 1]] {
+            path.push(previous);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Searches the passed grid breadth first from every passed start at once. A step can
+/// only be taken between neighbouring cells for which the passed function, given the
+/// cell stepped from and the cell stepped to, returns true.
+pub fn breadth_first_search<T>(
+    grid: &Grid<T>,
+    starts: impl IntoIterator<Item = Point>,
+    can_step: impl Fn(&T, &T) -> bool,
+) -> Paths {
+    let mut steps = Grid::from_bounds(grid.min(), grid.max(), None);
+    let mut previous = Grid::from_bounds(grid.min(), grid.max(), None);
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if grid.contains(start) && steps[start].is_none() {
+            steps[start] = Some(0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(point) = queue.pop_front() {
+        let next_steps = steps[point].map(|steps| steps + 1);
+
+        for neighbour in point.neighbours() {
+            match grid.get(neighbour) {
+                Some(cell) if steps[neighbour].is_none() && can_step(&grid[point], cell) => {
+                    steps[neighbour] = next_steps;
+                    previous[neighbour] = Some(point);
+                    queue.push_back(neighbour);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    Paths { steps, previous }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::Point, grid::Grid, search::breadth_first_search};
+
+    #[test]
+    fn test_breadth_first_search() {
+        let grid = Grid::parse(".#.\n...\n##.", "`.` or `#`", |c| match c {
+            '.' => Some(true),
+            '#' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        let paths = breadth_first_search(&grid, [Point(0, 0)], |_, to| *to);
+
+        assert_eq!(paths.steps(Point(0, 0)), Some(0));
+        assert_eq!(paths.steps(Point(2, 0)), Some(4));
+        assert_eq!(paths.steps(Point(2, 2)), Some(4));
+        assert_eq!(paths.steps(Point(1, 0)), None);
+        assert_eq!(paths.reached().count(), 6);
+        assert_eq!(
+            paths.path(Point(2, 2)),
+            Some(vec![
+                Point(0, 0),
+                Point(0, 1),
+                Point(1, 1),
+                Point(2, 1),
+                Point(2, 2)
+            ])
+        );
+        assert_eq!(paths.path(Point(0, 2)), None);
+
+        let paths = breadth_first_search(&grid, [Point(0, 0), Point(2, 2)], |_, to| *to);
+        assert_eq!(paths.steps(Point(2, 0)), Some(2));
+    }
+}
@@ -0,0 +1,58 @@
+use std::process;
+
+use crate::{report, Answer, BlankLines, NormalizedInput, ParseError, Part};
+
+/// Solves a single day. The puzzle input is parsed once and the parsed input is
+/// then shared by both parts.
+pub trait Solver {
+    type Input;
+
+    /// How the puzzle input uses blank lines, used to normalize it before it is parsed
+    const BLANK_LINES: BlankLines = BlankLines::Ignored;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Answer;
+
+    fn part_two(input: &Self::Input) -> Answer;
+}
+
+/// Parses the passed data read from the passed file name with the passed solver and
+/// reports the answer of both parts. Exits with a diagnostic if the data cannot be parsed.
+pub fn solve<S: Solver>(file_name: &str, data: &str) {
+    match get_answers::<S>(data, &[Part::One, Part::Two]) {
+        Ok(answers) => {
+            for (part, answer) in answers {
+                report(part, answer);
+            }
+        }
+        Err(error) => {
+            eprintln!("{}", error.diagnostic(file_name, data));
+            process::exit(1);
+        }
+    }
+}
+
+/// Normalizes the passed data and parses it with the passed solver. Errors point into
+/// the passed data rather than the normalized data.
+pub fn parse_input<S: Solver>(data: &str) -> Result<S::Input, ParseError> {
+    let normalized = NormalizedInput::new(data, S::BLANK_LINES);
+
+    S::parse(&normalized.text).map_err(|error| normalized.locate(error))
+}
+
+/// Parses the passed data with the passed solver and returns the answer of every passed part.
+pub fn get_answers<S: Solver>(
+    data: &str,
+    parts: &[Part],
+) -> Result<Vec<(Part, Answer)>, ParseError> {
+    let input = parse_input::<S>(data)?;
+
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            Part::One => (*part, S::part_one(&input)),
+            Part::Two => (*part, S::part_two(&input)),
+        })
+        .collect())
+}
@@ -0,0 +1,387 @@
+use aoc_common::{input_file, parse_token, Answer, BlankLines, ParseError, Solver};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt,
+    io::{self, BufRead},
+};
+
+pub const FILE_NAME: &str = input_file!();
+const PART_TWO_ELF_COUNT: usize = 3;
+/// Width of the calorie ranges the statistics count elves in
+const HISTOGRAM_BUCKET_WIDTH: u64 = 10_000;
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Input = ElfInventory;
+
+    const BLANK_LINES: BlankLines = BlankLines::Separator;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        ElfInventory::new(data)
+    }
+
+    fn part_one(inventory: &Self::Input) -> Answer {
+        sum_totals(&inventory.top(1)).into()
+    }
+
+    fn part_two(inventory: &Self::Input) -> Answer {
+        sum_totals(&inventory.top(PART_TWO_ELF_COUNT)).into()
+    }
+}
+
+/// The food carried by a single elf
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Elf {
+    /// Position of the elf in the input, starting from 1
+    pub number: usize,
+    /// Calories of every item carried added together
+    pub total: u64,
+    /// Number of items carried
+    pub items: usize,
+}
+
+/// Every elf in the order they appear in the input
+pub struct ElfInventory {
+    elves: Vec<Elf>,
+}
+
+impl ElfInventory {
+    /// Parses the calories of every item, one per line, with a blank line between the
+    /// items of two elves
+    fn new(data: &str) -> Result<ElfInventory, ParseError> {
+        let mut tally = ElfTally::default();
+        let mut elves = vec![];
+
+        for (line_index, line) in data.lines().enumerate() {
+            elves.extend(tally.add_line(line_index, line)?);
+        }
+        elves.extend(tally.finish());
+
+        Ok(ElfInventory { elves })
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /// The passed number of elves carrying the most calories, from the most to the least.
+    /// Elves carrying the same calories are ordered by their number.
+    pub fn top(&self, count: usize) -> Vec<Elf> {
+        let mut top = TopElves::new(count);
+        for elf in &self.elves {
+            top.push(*elf);
+        }
+        top.into_sorted()
+    }
+
+    /// Mean calories carried per elf, if there are any elves
+    pub fn mean(&self) -> Option<f64> {
+        match self.elves.is_empty() {
+            true => None,
+            false => Some(sum_totals(&self.elves) as f64 / self.elves.len() as f64),
+        }
+    }
+
+    /// Median calories carried per elf, if there are any elves
+    pub fn median(&self) -> Option<f64> {
+        let mut totals: Vec<u64> = self.elves.iter().map(|elf| elf.total).collect();
+        totals.sort();
+
+        let middle = totals.len() / 2;
+        match totals.len() {
+            0 => None,
+            len if len.is_multiple_of(2) => {
+                Some((totals[middle - 1] + totals[middle]) as f64 / 2.0)
+            }
+            _ => Some(totals[middle] as f64),
+        }
+    }
+
+    /// Number of elves carrying calories within every range of the passed width, from
+    /// the range of the fewest calories carried to the range of the most. Every range is
+    /// given by its lowest calories.
+    pub fn histogram(&self, bucket_width: u64) -> Vec<(u64, usize)> {
+        let buckets = self.elves.iter().map(|elf| elf.total / bucket_width);
+        let (min, max) = match (buckets.clone().min(), buckets.clone().max()) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return vec![],
+        };
+
+        let mut counts = vec![0; (max - min + 1) as usize];
+        for bucket in buckets {
+            counts[(bucket - min) as usize] += 1;
+        }
+
+        (min..=max)
+            .map(|bucket| bucket * bucket_width)
+            .zip(counts)
+            .collect()
+    }
+}
+
+/// Keeps the elves carrying the most calories out of every elf pushed, holding no more
+/// elves than are kept
+pub struct TopElves {
+    count: usize,
+    /// The smallest of the kept elves is at the top of the heap so it is the one replaced
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>, usize)>>,
+}
+
+impl TopElves {
+    /// Keeps the passed number of elves
+    pub fn new(count: usize) -> TopElves {
+        TopElves {
+            count,
+            heap: BinaryHeap::with_capacity(count + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: Elf) {
+        self.heap
+            .push(Reverse((elf.total, Reverse(elf.number), elf.items)));
+        if self.heap.len() > self.count {
+            self.heap.pop();
+        }
+    }
+
+    /// The kept elves from the most calories to the least
+    pub fn into_sorted(self) -> Vec<Elf> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(number), items))| Elf {
+                number,
+                total,
+                items,
+            })
+            .collect()
+    }
+}
+
+/// Adds up the calories of the elf whose items are being read, a line at a time
+#[derive(Default)]
+struct ElfTally {
+    /// Number of elves whose items have all been read
+    finished: usize,
+    /// The elf whose items are being read, if any of its items have been read
+    elf: Option<Elf>,
+}
+
+impl ElfTally {
+    /// Adds the item on the passed line to the current elf. A blank line finishes the
+    /// current elf, which is returned.
+    fn add_line(&mut self, line_index: usize, line: &str) -> Result<Option<Elf>, ParseError> {
+        if line.is_empty() {
+            return Ok(self.finish());
+        }
+
+        let calories: u64 = parse_token(line_index, line, line, "calories as a number")?;
+        let elf = self.elf.get_or_insert(Elf {
+            number: self.finished + 1,
+            total: 0,
+            items: 0,
+        });
+        elf.total += calories;
+        elf.items += 1;
+
+        Ok(None)
+    }
+
+    /// Finishes the current elf, which is returned. The last elf is not followed by a
+    /// blank line so it must be finished once every line is read.
+    fn finish(&mut self) -> Option<Elf> {
+        let elf = self.elf.take();
+        if elf.is_some() {
+            self.finished += 1;
+        }
+        elf
+    }
+}
+
+/// Why streamed calories could not be read
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "{}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Reads the calories of every item from the passed reader a line at a time and keeps
+/// the passed number of elves carrying the most calories, from the most to the least.
+/// Only the current line and the kept elves are held in memory so the input can be far
+/// larger than memory, such as a generated input piped to stdin. Lines are read as
+/// they would be normalized so the elves are the same as `ElfInventory::top` finds.
+pub fn stream_top_elves(mut reader: impl BufRead, count: usize) -> Result<Vec<Elf>, StreamError> {
+    let mut tally = ElfTally::default();
+    let mut top = TopElves::new(count);
+    let mut line = String::new();
+    let mut line_index = 0;
+
+    while reader.read_line(&mut line)? > 0 {
+        if let Some(elf) = tally.add_line(line_index, line.trim_end())? {
+            top.push(elf);
+        }
+        line.clear();
+        line_index += 1;
+    }
+    if let Some(elf) = tally.finish() {
+        top.push(elf);
+    }
+
+    Ok(top.into_sorted())
+}
+
+/// The answers of both parts found by streaming the passed reader
+pub fn stream_answers(reader: impl BufRead) -> Result<(Answer, Answer), StreamError> {
+    let top = stream_top_elves(reader, PART_TWO_ELF_COUNT)?;
+
+    Ok((
+        sum_totals(&top[..top.len().min(1)]).into(),
+        sum_totals(&top).into(),
+    ))
+}
+
+fn sum_totals(elves: &[Elf]) -> u64 {
+    elves.iter().map(|elf| elf.total).sum()
+}
+
+/// Renders the number of elves, the mean and median calories and a histogram of the
+/// calories carried
+pub fn format_statistics(inventory: &ElfInventory) -> String {
+    let mut statistics = format!("Elves: {}\n", inventory.elves().len());
+    let histogram = inventory.histogram(HISTOGRAM_BUCKET_WIDTH);
+    if let (Some(mean), Some(median)) = (inventory.mean(), inventory.median()) {
+        statistics.push_str(&format!("Mean calories: {:.1}\n", mean));
+        statistics.push_str(&format!("Median calories: {:.1}\n", median));
+    }
+    if !histogram.is_empty() {
+        statistics.push_str("Elves by calories:\n");
+    }
+    for (calories, elves) in histogram {
+        statistics.push_str(&format!(
+            "{:>7}-{:<7} {}\n",
+            calories,
+            calories + HISTOGRAM_BUCKET_WIDTH - 1,
+            elves
+        ));
+    }
+    statistics.pop();
+    statistics
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{get_answers, parse_input, Answer, Part, Solver};
+
+    use crate::{format_statistics, stream_answers, stream_top_elves, Day1, Elf, StreamError};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day1>(EXAMPLE).unwrap();
+        assert_eq!(Day1::part_one(&input), Answer::from(24000));
+        assert_eq!(Day1::part_two(&input), Answer::from(45000));
+    }
+
+    #[test]
+    fn test_top() {
+        let inventory = parse_input::<Day1>("5\n\n3\n4\n\n7\n\n1").unwrap();
+        assert_eq!(
+            inventory.top(2),
+            [
+                Elf {
+                    number: 2,
+                    total: 7,
+                    items: 2
+                },
+                Elf {
+                    number: 3,
+                    total: 7,
+                    items: 1
+                }
+            ]
+        );
+        assert_eq!(inventory.top(10).len(), 4);
+        assert!(inventory.top(0).is_empty());
+    }
+
+    #[test]
+    fn test_statistics() {
+        let inventory = parse_input::<Day1>(EXAMPLE).unwrap();
+        assert_eq!(inventory.elves().len(), 5);
+        assert_eq!(inventory.elves()[1].items, 1);
+        assert_eq!(inventory.mean(), Some(11000.0));
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(
+            inventory.histogram(10_000),
+            [(0, 2), (10_000, 2), (20_000, 1)]
+        );
+        assert_eq!(
+            format_statistics(&inventory),
+            "Elves: 5\n\
+             Mean calories: 11000.0\n\
+             Median calories: 10000.0\n\
+             Elves by calories:\n      \
+             0-9999    2\n  \
+             10000-19999   2\n  \
+             20000-29999   1"
+        );
+
+        let inventory = parse_input::<Day1>("1\n\n3\n\n9\n\n8").unwrap();
+        assert_eq!(inventory.median(), Some(5.5));
+        assert_eq!(inventory.histogram(4), [(0, 2), (4, 0), (8, 2)]);
+
+        let inventory = parse_input::<Day1>("").unwrap();
+        assert_eq!((inventory.mean(), inventory.median()), (None, None));
+        assert!(inventory.histogram(4).is_empty());
+        assert_eq!(Day1::part_one(&inventory), Answer::from(0));
+    }
+
+    #[test]
+    fn test_stream() {
+        let answers = get_answers::<Day1>(EXAMPLE, &[Part::One, Part::Two]).unwrap();
+        assert_eq!(
+            stream_answers(EXAMPLE.as_bytes()).unwrap(),
+            (answers[0].1.clone(), answers[1].1.clone())
+        );
+
+        let data = "\r\n5\r\n\r\n\r\n3 \r\n4\n\n7\n";
+        let inventory = parse_input::<Day1>(data).unwrap();
+        assert_eq!(
+            stream_top_elves(data.as_bytes(), 2).unwrap(),
+            inventory.top(2)
+        );
+        assert_eq!(
+            stream_answers("".as_bytes()).unwrap(),
+            (Answer::from(0), Answer::from(0))
+        );
+
+        match stream_top_elves("1\n\nx".as_bytes(), 1) {
+            Err(StreamError::Parse(error)) => assert_eq!((error.line, error.column), (3, 1)),
+            _ => panic!("expected a parse error"),
+        }
+    }
+}
@@ -1,48 +1,33 @@
-use std::fs;
+use aoc_common::{parse_input, read_input_or_exit, report, solve, Part, STDIN_FILE_NAME};
+use day1::{format_statistics, stream_answers, Day1, FILE_NAME};
+use std::{env, io, process};
 
-const FILE_NAME: &str = "data1.txt";
+/// Streams the puzzle input from stdin rather than reading all of it first
+const STREAM_FLAG: &str = "--stream";
+/// Also reports the mean and median calories and a histogram of the calories carried
+const REPORT_FLAG: &str = "--report";
 
 fn main() {
-    let mut data = fs::read_to_string(FILE_NAME).expect("Something went wrong reading the file");
-    data.pop();
-
-    part_one(&data);
-    part_two(&data);
-}
-
-fn part_one(data: &str) {
-    let mut total = 0;
-    let mut max_total = 0;
-
-    for line in data.lines() {
-        if total > max_total {
-            max_total = total;
-        }
-
-        if line.is_empty() {
-            total = 0;
-        } else {
-            total += line.parse::<i32>().unwrap();
+    if env::args().skip(1).any(|arg| arg == STREAM_FLAG) {
+        match stream_answers(io::stdin().lock()) {
+            Ok((part_one, part_two)) => {
+                report(Part::One, part_one);
+                report(Part::Two, part_two);
+            }
+            Err(error) => {
+                eprintln!("error: {}:{}", STDIN_FILE_NAME, error);
+                process::exit(1);
+            }
         }
+        return;
     }
 
-    println!("Part one: {}", max_total);
-}
-
-fn part_two(data: &str) {
-    let mut total = 0;
-    let mut max_totals = vec![];
+    let data = read_input_or_exit(FILE_NAME);
 
-    for line in data.lines() {
-        if line.is_empty() {
-            max_totals.push(total);
-            max_totals.sort_by(|a, b| a.cmp(b).reverse());
-            max_totals.truncate(3);
-            total = 0;
-        } else {
-            total += line.parse::<i32>().unwrap();
+    if env::args().skip(1).any(|arg| arg == REPORT_FLAG) {
+        if let Ok(inventory) = parse_input::<Day1>(&data) {
+            println!("{}", format_statistics(&inventory));
         }
     }
-
-    println!("Part two: {}", max_totals.iter().sum::<i32>());
+    solve::<Day1>(FILE_NAME, &data);
 }
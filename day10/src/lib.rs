@@ -0,0 +1,171 @@
+use aoc_common::{input_file, parse_token, Answer, ParseError, Solver};
+use std::collections::HashSet;
+
+pub const FILE_NAME: &str = input_file!();
+const ADDX_TOKEN: &str = "addx";
+const NOOP_TOKEN: &str = "noop";
+const SUM_CYCLES: [i32; 6] = [20, 60, 100, 140, 180, 220];
+
+// part two
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+const LIT_PIXEL: &str = "#";
+const DARK_PIXEL: &str = ".";
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines()
+            .enumerate()
+            .map(|(i, line)| parse_line(i, line))
+            .collect()
+    }
+
+    fn part_one(instructions: &Self::Input) -> Answer {
+        let sum_cycles: HashSet<i32> = SUM_CYCLES.into_iter().collect();
+        let mut sum = 0;
+        let mut cycle = 1;
+        let mut x = 1;
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Addx(value) => {
+                    cycle += 1;
+
+                    if sum_cycles.contains(&cycle) {
+                        sum += x * cycle;
+                    }
+
+                    cycle += 1;
+                    x += value;
+                }
+                Instruction::Noop => cycle += 1,
+            }
+
+            if sum_cycles.contains(&cycle) {
+                sum += x * cycle;
+            }
+        }
+
+        sum.into()
+    }
+
+    fn part_two(instructions: &Self::Input) -> Answer {
+        let mut crt = String::new();
+        let mut cycle = 0;
+        let mut sprite_position = 1;
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Addx(value) => {
+                    cycle += 1;
+                    crt.push_str(get_pixel(sprite_position, cycle - 1));
+                    cycle += 1;
+                    crt.push_str(get_pixel(sprite_position, cycle - 1));
+                    sprite_position += value;
+                }
+                Instruction::Noop => {
+                    cycle += 1;
+                    crt.push_str(get_pixel(sprite_position, cycle - 1));
+                }
+            }
+        }
+
+        match crt.is_empty() {
+            true => Answer::Unsolved,
+            false => render_crt(&crt).into(),
+        }
+    }
+}
+
+/// Splits the CRT pixels into rows of CRT_WIDTH pixels separated by newlines. Only the
+/// rows the program drew are rendered, the last of which may be cut short, and pixels
+/// drawn after the last row are left out.
+fn render_crt(crt: &str) -> String {
+    crt.as_bytes()
+        .chunks(CRT_WIDTH)
+        .take(CRT_HEIGHT)
+        .map(|row| String::from_utf8_lossy(row))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn get_pixel(sprite_position: i32, pixel_position: i32) -> &'static str {
+    let pixel_position = pixel_position % (CRT_WIDTH as i32);
+    if sprite_position == pixel_position
+        || sprite_position + 1 == pixel_position
+        || sprite_position - 1 == pixel_position
+    {
+        LIT_PIXEL
+    } else {
+        DARK_PIXEL
+    }
+}
+
+fn parse_line(line_index: usize, line: &str) -> Result<Instruction, ParseError> {
+    let tokens: Vec<&str> = line.split(' ').collect();
+
+    if tokens[0] == ADDX_TOKEN {
+        match tokens.get(1) {
+            Some(token) => Ok(Instruction::Addx(parse_token(
+                line_index,
+                line,
+                token,
+                "a value to add",
+            )?)),
+            None => Err(ParseError::end_of_line(line_index, line, "a value to add")),
+        }
+    } else if tokens[0] == NOOP_TOKEN {
+        Ok(Instruction::Noop)
+    } else {
+        Err(ParseError::new(
+            line_index,
+            line,
+            tokens[0],
+            "`addx <value>` or `noop`",
+        ))
+    }
+}
+
+pub enum Instruction {
+    Addx(i32),
+    Noop,
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse_input, Answer, Solver};
+
+    use crate::Day10;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day10>(EXAMPLE).unwrap();
+        assert_eq!(Day10::part_one(&input), Answer::from(13140));
+        assert_eq!(
+            Day10::part_two(&input),
+            Answer::from(
+                "##..##..##..##..##..##..##..##..##..##..\n\
+             ###...###...###...###...###...###...###.\n\
+             ####....####....####....####....####....\n\
+             #####.....#####.....#####.....#####.....\n\
+             ######......######......######......####\n\
+             #######.......#######.......#######....."
+            )
+        );
+    }
+
+    #[test]
+    fn test_short_program() {
+        let input = parse_input::<Day10>("").unwrap();
+        assert_eq!(Day10::part_two(&input), Answer::Unsolved);
+
+        let input = parse_input::<Day10>("noop\naddx 5").unwrap();
+        assert_eq!(Day10::part_two(&input), Answer::from("###"));
+    }
+}
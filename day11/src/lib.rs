@@ -0,0 +1,431 @@
+use aoc_common::{input_file, parse_token, Answer, BlankLines, ParseError, Solver};
+
+pub const FILE_NAME: &str = input_file!();
+const PART_ONE_ROUNDS: u128 = 20;
+const PART_ONE_WORRY_MODIFIER_VALUE: u128 = 3;
+const PART_TWO_ROUNDS: u128 = 10000;
+
+// monkey constants
+const MONKEY_LINE_COUNT: usize = 6;
+const MONKEY_PREFIX: &str = "Monkey ";
+const ITEMS_PREFIX: &str = "  Starting items:";
+const OPERATION_PREFIX: &str = "  Operation: new = old ";
+const TEST_PREFIX: &str = "  Test: divisible by ";
+const TRUE_MONKEY_PREFIX: &str = "    If true: throw to monkey ";
+const FALSE_MONKEY_PREFIX: &str = "    If false: throw to monkey ";
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<Monkey>;
+
+    const BLANK_LINES: BlankLines = BlankLines::Separator;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(data)
+    }
+
+    fn part_one(monkeys: &Self::Input) -> Answer {
+        let mut monkeys = monkeys.clone();
+        match get_monkey_business(&mut monkeys, PART_ONE_ROUNDS, WorryModifier::PartOne) {
+            Some(monkey_business) => monkey_business.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part_two(monkeys: &Self::Input) -> Answer {
+        let mut monkeys = monkeys.clone();
+        // worry levels only need to stay divisible by the same divisors, which holds
+        // when they are kept modulo the product of every divisor
+        let modulus = monkeys.iter().map(|monkey| monkey.test.divisor).product();
+        match get_monkey_business(
+            &mut monkeys,
+            PART_TWO_ROUNDS,
+            WorryModifier::PartTwo(modulus),
+        ) {
+            Some(monkey_business) => monkey_business.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+/// Monkey business is the product of the two highest inspection counts after all rounds,
+/// so there is none with fewer than two monkeys
+fn get_monkey_business(
+    monkeys: &mut [Monkey],
+    rounds: u128,
+    worry_modifier: WorryModifier,
+) -> Option<usize> {
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            for j in 0..monkeys[i].items.len() {
+                let mut worry_level;
+                let divisor;
+                let true_monkey_id;
+                let false_monkey_id;
+
+                {
+                    let monkey = &monkeys[i];
+                    true_monkey_id = monkey.test.true_monkey_id.0;
+                    false_monkey_id = monkey.test.false_monkey_id.0;
+                    let item = monkey.items[j];
+
+                    let operation_value = match monkey.operation_value {
+                        OperationValue::Old => item,
+                        OperationValue::Number(number) => number as u128,
+                    };
+
+                    worry_level = match monkey.operation {
+                        Operation::Add => item + operation_value,
+                        Operation::Multiply => item * operation_value,
+                    };
+
+                    worry_level = modify_worry_level(worry_level, &worry_modifier);
+                    divisor = monkey.test.divisor;
+                }
+
+                match (worry_level % divisor) == 0 {
+                    true => monkeys[true_monkey_id as usize].items.push(worry_level),
+                    false => monkeys[false_monkey_id as usize].items.push(worry_level),
+                }
+
+                monkeys[i].inspection_count += 1;
+            }
+            monkeys[i].items.clear();
+        }
+    }
+
+    let mut inspection_counts = vec![];
+    for monkey in monkeys {
+        inspection_counts.push(monkey.inspection_count)
+    }
+    inspection_counts.sort();
+    inspection_counts.reverse();
+    match inspection_counts[..] {
+        [first, second, ..] => Some(first * second),
+        _ => None,
+    }
+}
+
+enum WorryModifier {
+    PartOne,
+    PartTwo(u128),
+}
+
+fn modify_worry_level(worry: u128, worry_modifier: &WorryModifier) -> u128 {
+    match worry_modifier {
+        WorryModifier::PartOne => worry / PART_ONE_WORRY_MODIFIER_VALUE,
+        WorryModifier::PartTwo(modulus) => worry % modulus,
+    }
+}
+
+fn parse_monkeys(data: &str) -> Result<Vec<Monkey>, ParseError> {
+    let lines: Vec<&str> = data.lines().collect();
+    let mut monkeys = vec![];
+
+    // every monkey is described by MONKEY_LINE_COUNT lines followed by a blank line
+    for first_line_index in (0..lines.len()).step_by(MONKEY_LINE_COUNT + 1) {
+        monkeys.push(parse_monkey(&lines, first_line_index)?);
+
+        let separator_index = first_line_index + MONKEY_LINE_COUNT;
+        match lines.get(separator_index) {
+            Some(line) if !line.is_empty() => {
+                return Err(ParseError::new(
+                    separator_index,
+                    line,
+                    line,
+                    "a blank line between monkeys",
+                ))
+            }
+            _ => (),
+        }
+    }
+
+    // monkeys can only throw to monkeys that exist, which are only known once every
+    // monkey is parsed
+    for (i, monkey) in monkeys.iter().enumerate() {
+        let first_line_index = i * (MONKEY_LINE_COUNT + 1);
+        check_monkey_id(
+            &lines,
+            first_line_index + 4,
+            &monkey.test.true_monkey_id,
+            monkeys.len(),
+        )?;
+        check_monkey_id(
+            &lines,
+            first_line_index + 5,
+            &monkey.test.false_monkey_id,
+            monkeys.len(),
+        )?;
+    }
+
+    Ok(monkeys)
+}
+
+/// Returns an error pointing at the monkey id ending the line at the passed index if
+/// there is no monkey with that id
+fn check_monkey_id(
+    lines: &[&str],
+    line_index: usize,
+    id: &MonkeyID,
+    monkey_count: usize,
+) -> Result<(), ParseError> {
+    match id.0 < monkey_count as u128 {
+        true => Ok(()),
+        false => {
+            let line = lines[line_index];
+            let token = line.rsplit(' ').next().unwrap_or(line);
+            Err(ParseError::new(
+                line_index,
+                line,
+                token,
+                &format!("a monkey id below {}", monkey_count),
+            ))
+        }
+    }
+}
+
+fn parse_monkey(lines: &[&str], first_line_index: usize) -> Result<Monkey, ParseError> {
+    // monkeys are listed in id order so the id line is only checked
+    strip_line_prefix(lines, first_line_index, MONKEY_PREFIX, "`Monkey <id>:`")?;
+
+    // parse items
+    let line_index = first_line_index + 1;
+    let (line, tokens) = strip_line_prefix(
+        lines,
+        line_index,
+        ITEMS_PREFIX,
+        "`  Starting items: <item>, <item>, ...`",
+    )?;
+    // a monkey may start without items, leaving nothing after the prefix
+    let items = tokens
+        .trim_start()
+        .split_terminator(", ")
+        .map(|t| parse_token::<u128>(line_index, line, t, "an item worry level"))
+        .collect::<Result<Vec<u128>, ParseError>>()?;
+
+    // parse operation and operation value
+    let line_index = first_line_index + 2;
+    let operation_form = "`  Operation: new = old <* or +> <old or number>`";
+    let (line, tokens) = strip_line_prefix(lines, line_index, OPERATION_PREFIX, operation_form)?;
+    let (operation, operation_value) = match tokens.split_once(' ') {
+        Some(tokens) => tokens,
+        None => return Err(ParseError::new(line_index, line, tokens, operation_form)),
+    };
+    let operation = match Operation::new(operation) {
+        Some(operation) => operation,
+        None => {
+            return Err(ParseError::new(
+                line_index,
+                line,
+                operation,
+                "an operation `*` or `+`",
+            ))
+        }
+    };
+    let operation_value = match operation_value {
+        "old" => OperationValue::Old,
+        number => {
+            OperationValue::Number(parse_token(line_index, line, number, "`old` or a number")?)
+        }
+    };
+
+    // parse test
+    let line_index = first_line_index + 3;
+    let (line, token) = strip_line_prefix(
+        lines,
+        line_index,
+        TEST_PREFIX,
+        "`  Test: divisible by <divisor>`",
+    )?;
+    let divisor = parse_token::<u128>(line_index, line, token, "a divisor")?;
+    if divisor == 0 {
+        return Err(ParseError::new(
+            line_index,
+            line,
+            token,
+            "a divisor above 0",
+        ));
+    }
+
+    let line_index = first_line_index + 4;
+    let (line, token) = strip_line_prefix(
+        lines,
+        line_index,
+        TRUE_MONKEY_PREFIX,
+        "`    If true: throw to monkey <id>`",
+    )?;
+    let true_monkey_id = parse_token::<u128>(line_index, line, token, "a monkey id")?;
+    let true_monkey_id = MonkeyID::new(true_monkey_id);
+
+    let line_index = first_line_index + 5;
+    let (line, token) = strip_line_prefix(
+        lines,
+        line_index,
+        FALSE_MONKEY_PREFIX,
+        "`    If false: throw to monkey <id>`",
+    )?;
+    let false_monkey_id = parse_token::<u128>(line_index, line, token, "a monkey id")?;
+    let false_monkey_id = MonkeyID::new(false_monkey_id);
+
+    Ok(Monkey::new(
+        items,
+        operation,
+        operation_value,
+        Test::new(divisor, true_monkey_id, false_monkey_id),
+    ))
+}
+
+/// Returns the line at the passed index and the remainder of the line after the passed prefix
+fn strip_line_prefix<'a>(
+    lines: &[&'a str],
+    line_index: usize,
+    prefix: &str,
+    expected: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    let line = match lines.get(line_index) {
+        Some(line) => *line,
+        None => return Err(ParseError::end_of_line(line_index, "", expected)),
+    };
+
+    match line.strip_prefix(prefix) {
+        Some(remainder) => Ok((line, remainder)),
+        None => Err(ParseError::new(line_index, line, line, expected)),
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<u128>,
+    operation: Operation,
+    operation_value: OperationValue,
+    test: Test,
+    inspection_count: usize,
+}
+
+impl Monkey {
+    fn new(
+        items: Vec<u128>,
+        operation: Operation,
+        operation_value: OperationValue,
+        test: Test,
+    ) -> Self {
+        Monkey {
+            items,
+            operation,
+            operation_value,
+            test,
+            inspection_count: 0,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct MonkeyID(u128);
+
+impl MonkeyID {
+    fn new(id: u128) -> Self {
+        MonkeyID(id)
+    }
+}
+
+#[derive(Clone)]
+struct Test {
+    divisor: u128,
+    true_monkey_id: MonkeyID,
+    false_monkey_id: MonkeyID,
+}
+
+impl Test {
+    fn new(divisor: u128, true_monkey_id: MonkeyID, false_monkey_id: MonkeyID) -> Self {
+        Test {
+            divisor,
+            true_monkey_id,
+            false_monkey_id,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    fn new(string: &str) -> Option<Self> {
+        if string == "*" {
+            Some(Operation::Multiply)
+        } else if string == "+" {
+            Some(Operation::Add)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone)]
+enum OperationValue {
+    Old,
+    Number(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse_input, Answer, Solver};
+
+    use crate::Day11;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day11>(EXAMPLE).unwrap();
+        assert_eq!(Day11::part_one(&input), Answer::from(10605));
+        assert_eq!(Day11::part_two(&input), Answer::from(2713310158_usize));
+    }
+
+    #[test]
+    fn test_missing_monkey() {
+        let data: Vec<&str> = EXAMPLE.lines().take(7).collect();
+        let error = parse_input::<Day11>(&data.join("\n")).err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (5, 30, "2")
+        );
+        assert_eq!(error.expected, "a monkey id below 1");
+    }
+
+    #[test]
+    fn test_zero_divisor() {
+        let data = EXAMPLE.replacen("divisible by 23", "divisible by 0", 1);
+        let error = parse_input::<Day11>(&data).err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (4, 22, "0")
+        );
+        assert_eq!(error.expected, "a divisor above 0");
+    }
+
+    #[test]
+    fn test_single_monkey() {
+        let data = EXAMPLE
+            .lines()
+            .take(6)
+            .collect::<Vec<&str>>()
+            .join("\n")
+            .replace("monkey 2", "monkey 0")
+            .replace("monkey 3", "monkey 0");
+        let input = parse_input::<Day11>(&data).unwrap();
+        assert_eq!(Day11::part_one(&input), Answer::Unsolved);
+        assert_eq!(Day11::part_two(&input), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_no_starting_items() {
+        let data = EXAMPLE.replacen("  Starting items: 79, 98", "  Starting items: ", 1);
+        let input = parse_input::<Day11>(&data).unwrap();
+        assert!(input[0].items.is_empty());
+        assert_eq!(input[1].items, [54, 65, 75, 74]);
+    }
+}
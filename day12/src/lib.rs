@@ -0,0 +1,199 @@
+use aoc_common::{
+    geometry::Point,
+    grid::Grid,
+    input_file,
+    search::{breadth_first_search, Paths},
+    Answer, ParseError, Solver,
+};
+
+/// No puzzle input is checked in for this day, so the example is solved by default
+pub const FILE_NAME: &str = input_file!("example.txt");
+const START_MARKER: char = 'S';
+const END_MARKER: char = 'E';
+const START_HEIGHT: char = 'a';
+const END_HEIGHT: char = 'z';
+const SQUARE_FORM: &str = "a height from `a` to `z`, `S` or `E`";
+/// A step can climb at most this much higher
+const MAX_CLIMB: u8 = 1;
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Heightmap;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Heightmap::new(data)
+    }
+
+    fn part_one(heightmap: &Self::Input) -> Answer {
+        match heightmap.climb().steps(heightmap.end) {
+            Some(steps) => steps.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part_two(heightmap: &Self::Input) -> Answer {
+        // descending from the end reaches every lowest square in a single search
+        let paths = heightmap.descend();
+        let lowest = get_height(START_HEIGHT);
+
+        match paths
+            .reached()
+            .filter(|(point, _)| heightmap.heights[*point] == lowest)
+            .map(|(_, steps)| steps)
+            .min()
+        {
+            Some(steps) => steps.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+pub struct Heightmap {
+    /// Height of every square, from 0 for `a` to 25 for `z`
+    heights: Grid<u8>,
+    start: Point,
+    end: Point,
+}
+
+impl Heightmap {
+    fn new(data: &str) -> Result<Heightmap, ParseError> {
+        let squares = Grid::parse(data, SQUARE_FORM, |c| match c {
+            START_HEIGHT..=END_HEIGHT | START_MARKER | END_MARKER => Some(c),
+            _ => None,
+        })?;
+        let start = find_marker(data, &squares, START_MARKER)?;
+        let end = find_marker(data, &squares, END_MARKER)?;
+
+        Ok(Heightmap {
+            heights: squares.map(|square| match *square {
+                START_MARKER => get_height(START_HEIGHT),
+                END_MARKER => get_height(END_HEIGHT),
+                height => get_height(height),
+            }),
+            start,
+            end,
+        })
+    }
+
+    /// Fewest steps from the start to every square, climbing at most one higher per step
+    fn climb(&self) -> Paths {
+        breadth_first_search(&self.heights, [self.start], |from, to| {
+            *to <= from + MAX_CLIMB
+        })
+    }
+
+    /// Fewest steps from every square to the end, found by walking the climb backwards
+    fn descend(&self) -> Paths {
+        breadth_first_search(&self.heights, [self.end], |from, to| {
+            *from <= to + MAX_CLIMB
+        })
+    }
+
+    /// A path with the fewest steps from the start to the end, including both
+    pub fn get_shortest_path(&self) -> Option<Vec<Point>> {
+        self.climb().path(self.end)
+    }
+}
+
+fn get_height(height: char) -> u8 {
+    height as u8 - START_HEIGHT as u8
+}
+
+/// Finds the only square holding the passed marker
+fn find_marker(data: &str, squares: &Grid<char>, marker: char) -> Result<Point, ParseError> {
+    let mut markers = squares
+        .iter()
+        .filter(|(_, square)| **square == marker)
+        .map(|(point, _)| point);
+
+    let point = match markers.next() {
+        Some(point) => point,
+        None => {
+            return Err(ParseError::end_of_line(
+                squares.height() - 1,
+                data.lines().last().unwrap_or(""),
+                &format!("a `{}` square", marker),
+            ))
+        }
+    };
+
+    match markers.next() {
+        Some(duplicate) => {
+            let line_index = duplicate.1 as usize;
+            let line = data.lines().nth(line_index).unwrap_or("");
+            let column = duplicate.0 as usize;
+            Err(ParseError::new(
+                line_index,
+                line,
+                &line[column..column + 1],
+                &format!("a single `{}` square", marker),
+            ))
+        }
+        None => Ok(point),
+    }
+}
+
+/// Draws the passed path over the heightmap as in the puzzle description. Every square
+/// on the path shows the direction of the next step, the end is `E` and every other
+/// square is `.`.
+pub fn render_path(heightmap: &Heightmap, path: &[Point]) -> String {
+    let mut map = heightmap.heights.map(|_| '.');
+
+    for step in path.windows(2) {
+        map[step[0]] = match (step[1].0 - step[0].0, step[1].1 - step[0].1) {
+            (1, 0) => '>',
+            (-1, 0) => '<',
+            (0, 1) => 'v',
+            _ => '^',
+        };
+    }
+    map[heightmap.end] = END_MARKER;
+
+    map.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{geometry::Point, parse_input, Answer, Solver};
+
+    use crate::{render_path, Day12, Heightmap};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day12>(EXAMPLE).unwrap();
+        assert_eq!(Day12::part_one(&input), Answer::from(31));
+        assert_eq!(Day12::part_two(&input), Answer::from(29));
+    }
+
+    #[test]
+    fn test_heightmap_new() {
+        let error = Heightmap::new("Sab\nzEz\nbcE").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+
+        let error = Heightmap::new("Sab\nzcz").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        assert!(Heightmap::new("Sab\nz1E").is_err());
+    }
+
+    #[test]
+    fn test_render_path() {
+        let heightmap = parse_input::<Day12>(EXAMPLE).unwrap();
+        assert_eq!(heightmap.get_shortest_path().unwrap().len(), 32);
+
+        let heightmap = Heightmap::new("Sbc\nzzd\nEfe").unwrap();
+        let path = [
+            Point(0, 0),
+            Point(1, 0),
+            Point(2, 0),
+            Point(2, 1),
+            Point(2, 2),
+            Point(1, 2),
+            Point(0, 2),
+        ];
+        assert_eq!(render_path(&heightmap, &path), ">>v\n..v\nE<<");
+    }
+}
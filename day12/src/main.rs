@@ -0,0 +1,26 @@
+use aoc_common::{parse_input, read_input_or_exit, solve};
+use day12::{render_path, Day12, FILE_NAME};
+use std::env;
+
+/// Also prints the heightmap with the shortest path from the start to the end drawn on it
+const PATH_FLAG: &str = "--path";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    // the first argument that is not a flag is the input, as day 12 has no puzzle input
+    // checked in
+    let file_name = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .map_or(FILE_NAME, String::as_str);
+    let data = read_input_or_exit(file_name);
+
+    if args.iter().any(|arg| arg == PATH_FLAG) {
+        if let Ok(heightmap) = parse_input::<Day12>(&data) {
+            if let Some(path) = heightmap.get_shortest_path() {
+                println!("{}", render_path(&heightmap, &path));
+            }
+        }
+    }
+    solve::<Day12>(file_name, &data);
+}
@@ -0,0 +1,275 @@
+use aoc_common::{
+    geometry::{Direction8, Point},
+    grid::Grid,
+    input_file, parse_token, Answer, ParseError, Solver,
+};
+use std::{
+    cmp::{max, min},
+    collections::HashSet,
+    fmt,
+    ops::Range,
+};
+
+pub const FILE_NAME: &str = input_file!();
+const POINT_SEPARATOR: &str = " -> ";
+const PATH_FORM: &str = "a rock path such as `498,4 -> 498,6 -> 496,6`";
+const SAND_SOURCE: Point = Point(500, 0);
+/// The floor is this far below the lowest rock
+const FLOOR_DEPTH: i32 = 2;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    /// Every rock, in a grid just large enough to hold every rock and the sand source
+    type Input = Grid<Material>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_material_grid(data)
+    }
+
+    fn part_one(material_grid: &Self::Input) -> Answer {
+        let mut material_grid = material_grid.clone();
+        drop_sand(&mut material_grid).into()
+    }
+
+    fn part_two(material_grid: &Self::Input) -> Answer {
+        // sand can spread at most one column sideways for every row it falls, but rocks
+        // may lie further sideways than that
+        let floor_y = material_grid.max().1 + FLOOR_DEPTH;
+        let mut floored_grid = Grid::from_bounds(
+            Point(
+                min(material_grid.min().0, SAND_SOURCE.0 - floor_y),
+                material_grid.min().1,
+            ),
+            Point(max(material_grid.max().0, SAND_SOURCE.0 + floor_y), floor_y),
+            Material::Air,
+        );
+        for (point, material) in material_grid.iter() {
+            floored_grid[point] = material.clone();
+        }
+        for x in floored_grid.min().0..=floored_grid.max().0 {
+            floored_grid[Point(x, floor_y)] = Material::Rock;
+        }
+
+        drop_sand(&mut floored_grid).into()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Material {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Material {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Material::Air => write!(f, "."),
+            Material::Rock => write!(f, "#"),
+            Material::Sand => write!(f, "o"),
+        }
+    }
+}
+
+/// Drops sand from the sand source until sand falls out of the grid or comes to rest
+/// on the source. Returns the number of units of sand that came to rest.
+fn drop_sand(material_grid: &mut Grid<Material>) -> usize {
+    let mut total_sand_positions = 0;
+
+    while let Some(sand_position) = get_resting_position(material_grid) {
+        material_grid[sand_position] = Material::Sand;
+        total_sand_positions += 1;
+
+        if sand_position == SAND_SOURCE {
+            break;
+        }
+    }
+
+    total_sand_positions
+}
+
+/// Where a unit of sand dropped from the sand source comes to rest, if it does not
+/// fall out of the grid
+fn get_resting_position(material_grid: &Grid<Material>) -> Option<Point> {
+    let mut current_sand_position = SAND_SOURCE;
+
+    'fall: loop {
+        for sand_position in get_sorted_sand_drop(current_sand_position) {
+            match material_grid.get(sand_position) {
+                None => return None,
+                Some(Material::Air) => {
+                    current_sand_position = sand_position;
+                    continue 'fall;
+                }
+                Some(_) => (),
+            }
+        }
+
+        return Some(current_sand_position);
+    }
+}
+
+/// Sand falls down, then down and to the left, then down and to the right
+fn get_sorted_sand_drop(p: Point) -> [Point; 3] {
+    [Direction8::D, Direction8::DL, Direction8::DR].map(|direction| p.step8(direction))
+}
+
+fn parse_material_grid(data: &str) -> Result<Grid<Material>, ParseError> {
+    let mut rocks = HashSet::new();
+    let lines = data.lines();
+
+    for (line_index, line) in lines.enumerate() {
+        let mut prior_point = None;
+        for token in line.split(POINT_SEPARATOR) {
+            let point = parse_point(line_index, line, token)?;
+            rocks.insert(point);
+
+            if let Some(prior_point) = prior_point {
+                if !shares_axis(&prior_point, &point) {
+                    return Err(ParseError::new(
+                        line_index,
+                        line,
+                        token,
+                        "a point in line with the prior point",
+                    ));
+                }
+
+                rocks.extend(points_between(&prior_point, &point));
+            }
+
+            prior_point = Some(point);
+        }
+    }
+
+    if rocks.is_empty() {
+        return Err(ParseError::end_of_line(0, "", PATH_FORM));
+    }
+
+    let mut min_point = SAND_SOURCE;
+    let mut max_point = SAND_SOURCE;
+    for rock in &rocks {
+        min_point = Point(min(min_point.0, rock.0), min(min_point.1, rock.1));
+        max_point = Point(max(max_point.0, rock.0), max(max_point.1, rock.1));
+    }
+
+    let mut material_grid = Grid::from_bounds(min_point, max_point, Material::Air);
+    for rock in rocks {
+        material_grid[rock] = Material::Rock;
+    }
+
+    Ok(material_grid)
+}
+
+fn parse_point(line_index: usize, line: &str, token: &str) -> Result<Point, ParseError> {
+    match token.split_once(',') {
+        Some((x, y)) => Ok(Point(
+            parse_token(line_index, line, x, "an x coordinate")?,
+            parse_token(line_index, line, y, "a y coordinate")?,
+        )),
+        None => Err(ParseError::new(line_index, line, token, PATH_FORM)),
+    }
+}
+
+fn shares_axis(p1: &Point, p2: &Point) -> bool {
+    p1.0 == p2.0 || p1.1 == p2.1
+}
+
+fn points_between(p1: &Point, p2: &Point) -> HashSet<Point> {
+    if p1 == p2 {
+        HashSet::new()
+    } else if p1.0 != p2.0 && p1.1 != p2.1 {
+        panic!("points must share an axis to find inner points");
+    } else if p1.0 != p2.0 {
+        let range = Range {
+            start: min(p1.0 + 1, p2.0 + 1),
+            end: max(p1.0, p2.0),
+        };
+        range.into_iter().map(|x| Point(x, p1.1)).collect()
+    } else if p1.1 != p2.1 {
+        let range = Range {
+            start: min(p1.1 + 1, p2.1 + 1),
+            end: max(p1.1, p2.1),
+        };
+        range.into_iter().map(|y| Point(p1.0, y)).collect()
+    } else {
+        panic!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{geometry::Point, parse_input, Answer, Solver};
+    use std::collections::HashSet;
+
+    use crate::{points_between, Day14};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day14>(EXAMPLE).unwrap();
+        assert_eq!(Day14::part_one(&input), Answer::from(24));
+        assert_eq!(Day14::part_two(&input), Answer::from(93));
+    }
+
+    #[test]
+    fn test_wide_rock() {
+        // the rock reaches further sideways than the floor under the sand
+        let input = parse_input::<Day14>("490,2 -> 510,2").unwrap();
+        assert_eq!(Day14::part_one(&input), Answer::from(4));
+        assert_eq!(Day14::part_two(&input), Answer::from(4));
+
+        let input = parse_input::<Day14>("490,1 -> 510,1").unwrap();
+        assert_eq!(Day14::part_two(&input), Answer::from(1));
+    }
+
+    #[test]
+    fn test_points_between() {
+        assert_eq!(points_between(&Point(0, 0), &Point(0, 0)), HashSet::new());
+
+        assert_eq!(points_between(&Point(1, 0), &Point(0, 0)), HashSet::new());
+        assert_eq!(
+            points_between(&Point(2, 0), &Point(0, 0)),
+            hashset(&[Point(1, 0)])
+        );
+        assert_eq!(
+            points_between(&Point(3, 0), &Point(0, 0)),
+            hashset(&[Point(1, 0), Point(2, 0)])
+        );
+
+        assert_eq!(points_between(&Point(0, 1), &Point(0, 0)), HashSet::new());
+        assert_eq!(
+            points_between(&Point(0, 2), &Point(0, 0)),
+            hashset(&[Point(0, 1)])
+        );
+        assert_eq!(
+            points_between(&Point(0, 3), &Point(0, 0)),
+            hashset(&[Point(0, 1), Point(0, 2)])
+        );
+
+        assert_eq!(points_between(&Point(0, 0), &Point(1, 0)), HashSet::new());
+        assert_eq!(
+            points_between(&Point(0, 0), &Point(2, 0)),
+            hashset(&[Point(1, 0)])
+        );
+        assert_eq!(
+            points_between(&Point(0, 0), &Point(3, 0)),
+            hashset(&[Point(1, 0), Point(2, 0)])
+        );
+
+        assert_eq!(points_between(&Point(0, 0), &Point(0, 1)), HashSet::new());
+        assert_eq!(
+            points_between(&Point(0, 0), &Point(0, 2)),
+            hashset(&[Point(0, 1)])
+        );
+        assert_eq!(
+            points_between(&Point(0, 0), &Point(0, 3)),
+            hashset(&[Point(0, 1), Point(0, 2)])
+        );
+    }
+
+    fn hashset(points: &[Point]) -> HashSet<Point> {
+        HashSet::from_iter(points.iter().cloned())
+    }
+}
@@ -0,0 +1,326 @@
+use aoc_common::{
+    geometry::Point,
+    input_file,
+    interval::{Interval, IntervalSet},
+    parse_token, Answer, ParseError, Solver,
+};
+use std::{collections::HashSet, ops::RangeInclusive};
+
+pub const FILE_NAME: &str = input_file!();
+const SENSOR_PREFIX: &str = "Sensor at ";
+const BEACON_SEPARATOR: &str = ": closest beacon is at ";
+const SENSOR_FORM: &str = "`Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`";
+/// Starts the optional first line overriding the row and search square, such as
+/// `Parameters: row=2000000, search_max=4000000`
+const PARAMETERS_PREFIX: &str = "Parameters:";
+const PARAMETER_FORM: &str = "`row=<y>` or `search_max=<max>`";
+/// Inputs whose sensors are all within this distance of the origin are taken as the
+/// example, which uses a much smaller row and search square than the real input
+const EXAMPLE_EXTENT: i32 = 1_000;
+const EXAMPLE_ROW: i32 = 10;
+const EXAMPLE_SEARCH_MAX: i32 = 20;
+const ROW: i32 = 2_000_000;
+const SEARCH_MAX: i32 = 4_000_000;
+const TUNING_FREQUENCY_MULTIPLIER: i64 = 4_000_000;
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = Scan;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part_one(scan: &Self::Input) -> Answer {
+        scan.count_no_beacon_positions(scan.row..=scan.row)[0].into()
+    }
+
+    fn part_two(scan: &Self::Input) -> Answer {
+        match get_tuning_frequency(&scan.sensors, scan.search_max) {
+            Some(tuning_frequency) => tuning_frequency.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+pub struct Scan {
+    sensors: Vec<Sensor>,
+    /// The row part one counts the positions where no beacon can be on
+    row: i32,
+    /// Part two searches every position with an x and y from 0 to this
+    search_max: i32,
+}
+
+impl Scan {
+    /// Number of positions where no beacon can be on every passed row, in row order
+    pub fn count_no_beacon_positions(&self, rows: RangeInclusive<i32>) -> Vec<usize> {
+        // every beacon is within range of its sensor so is always covered
+        let beacons: HashSet<Point> = self.sensors.iter().map(|sensor| sensor.beacon).collect();
+
+        rows.map(|y| {
+            get_row_coverage(&self.sensors, y).len() as usize
+                - beacons.iter().filter(|beacon| beacon.1 == y).count()
+        })
+        .collect()
+    }
+}
+
+fn parse(data: &str) -> Result<Scan, ParseError> {
+    let mut lines = data.lines().enumerate().peekable();
+    let mut row = None;
+    let mut search_max = None;
+    let mut sensors = vec![];
+
+    if let Some((line_index, line)) = lines.next_if(|(_, line)| line.starts_with(PARAMETERS_PREFIX))
+    {
+        // a bare `Parameters:` line overrides nothing
+        let parameters = line[PARAMETERS_PREFIX.len()..].trim_start();
+        for parameter in parameters.split_terminator(", ") {
+            match parameter.split_once('=') {
+                Some(("row", y)) => row = Some(parse_token(line_index, line, y, "a row")?),
+                Some(("search_max", max)) => {
+                    search_max = Some(parse_token(line_index, line, max, "a search maximum")?)
+                }
+                _ => return Err(ParseError::new(line_index, line, parameter, PARAMETER_FORM)),
+            }
+        }
+    }
+
+    for (line_index, line) in lines {
+        let (sensor_position, beacon_position) = match line
+            .strip_prefix(SENSOR_PREFIX)
+            .and_then(|positions| positions.split_once(BEACON_SEPARATOR))
+        {
+            Some(positions) => positions,
+            None => return Err(ParseError::new(line_index, line, line, SENSOR_FORM)),
+        };
+        let sensor_position = parse_point(line_index, line, sensor_position)?;
+        let beacon_position = parse_point(line_index, line, beacon_position)?;
+
+        sensors.push(Sensor::new(sensor_position, beacon_position));
+    }
+
+    let is_example = sensors
+        .iter()
+        .all(|sensor| sensor.position.chebyshev_distance(Point(0, 0)) <= EXAMPLE_EXTENT);
+    let (default_row, default_search_max) = match is_example {
+        true => (EXAMPLE_ROW, EXAMPLE_SEARCH_MAX),
+        false => (ROW, SEARCH_MAX),
+    };
+
+    Ok(Scan {
+        sensors,
+        row: row.unwrap_or(default_row),
+        search_max: search_max.unwrap_or(default_search_max),
+    })
+}
+
+/// Parses a position such as `x=-2, y=15`
+fn parse_point(line_index: usize, line: &str, position: &str) -> Result<Point, ParseError> {
+    let (x, y) = match position.split_once(", ") {
+        Some(coordinates) => coordinates,
+        None => {
+            return Err(ParseError::new(
+                line_index,
+                line,
+                position,
+                "`x=<x>, y=<y>`",
+            ))
+        }
+    };
+    let x = match x.strip_prefix("x=") {
+        Some(x) => parse_token(line_index, line, x, "an x coordinate")?,
+        None => return Err(ParseError::new(line_index, line, x, "`x=<x>`")),
+    };
+    let y = match y.strip_prefix("y=") {
+        Some(y) => parse_token(line_index, line, y, "a y coordinate")?,
+        None => return Err(ParseError::new(line_index, line, y, "`y=<y>`")),
+    };
+
+    Ok(Point(x, y))
+}
+
+pub struct Sensor {
+    position: Point,
+    /// The nearest beacon to the sensor
+    beacon: Point,
+    /// Distance to the nearest beacon. No other beacon is within this distance.
+    range: i32,
+}
+
+impl Sensor {
+    fn new(position: Point, beacon: Point) -> Sensor {
+        Sensor {
+            position,
+            beacon,
+            range: position.manhattan_distance(beacon),
+        }
+    }
+
+    /// The x coordinates on the passed row that are within range, if the row is
+    fn row_coverage(&self, y: i32) -> Option<Interval> {
+        let half_width = self.range - (self.position.1 - y).abs();
+        Interval::new(self.position.0 - half_width, self.position.0 + half_width)
+    }
+}
+
+/// The x coordinates on the passed row within range of any sensor
+fn get_row_coverage(sensors: &[Sensor], y: i32) -> IntervalSet {
+    sensors
+        .iter()
+        .filter_map(|sensor| sensor.row_coverage(y))
+        .collect()
+}
+
+/// The distress beacon is at the only position, with an x and y from 0 to the passed
+/// search maximum, that is further from every sensor than the sensor's nearest beacon.
+/// Being the only such position, it must lie just outside some sensor's range so only
+/// the perimeters of the sensor ranges are searched.
+fn get_tuning_frequency(sensors: &[Sensor], search_max: i32) -> Option<i64> {
+    let search_range = 0..=search_max;
+
+    for sensor in sensors {
+        for point in get_perimeter(sensor.position, sensor.range + 1) {
+            if search_range.contains(&point.0)
+                && search_range.contains(&point.1)
+                && sensors
+                    .iter()
+                    .all(|sensor| sensor.position.manhattan_distance(point) > sensor.range)
+            {
+                return Some(point.0 as i64 * TUNING_FREQUENCY_MULTIPLIER + point.1 as i64);
+            }
+        }
+    }
+
+    None
+}
+
+/// Every point at exactly the passed distance from the passed center
+fn get_perimeter(center: Point, distance: i32) -> impl Iterator<Item = Point> {
+    (0..distance).flat_map(move |i| {
+        let j = distance - i;
+        [
+            Point(center.0 + i, center.1 + j),
+            Point(center.0 + j, center.1 - i),
+            Point(center.0 - i, center.1 - j),
+            Point(center.0 - j, center.1 + i),
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{geometry::Point, parse_input, Answer, Solver};
+    use std::collections::HashSet;
+
+    use crate::{get_perimeter, get_row_coverage, parse, Day15, Sensor};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day15>(EXAMPLE).unwrap();
+        assert_eq!(Day15::part_one(&input), Answer::from(26));
+        assert_eq!(Day15::part_two(&input), Answer::from(56000011));
+    }
+
+    #[test]
+    fn test_parameters() {
+        let scan = parse("Sensor at x=0, y=0: closest beacon is at x=2, y=0").unwrap();
+        assert_eq!((scan.row, scan.search_max), (10, 20));
+
+        let scan = parse("Sensor at x=0, y=-3000: closest beacon is at x=2, y=0").unwrap();
+        assert_eq!((scan.row, scan.search_max), (2000000, 4000000));
+
+        let data = format!("Parameters: search_max=4, row=9\n{}", EXAMPLE);
+        let scan = parse(&data).unwrap();
+        assert_eq!((scan.row, scan.search_max), (9, 4));
+        assert_eq!(Day15::part_one(&scan), Answer::from(25));
+        assert_eq!(Day15::part_two(&scan), Answer::Unsolved);
+
+        let error = parse("Parameters: row=1, column=2").err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (1, 20, "column=2")
+        );
+        let error = parse("Parameters: row=x").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 17));
+
+        let data = format!("Parameters:\n{}", EXAMPLE);
+        let scan = parse_input::<Day15>(&data).unwrap();
+        assert_eq!((scan.row, scan.search_max), (10, 20));
+    }
+
+    #[test]
+    fn test_count_no_beacon_positions() {
+        let scan = parse_input::<Day15>(EXAMPLE).unwrap();
+        assert_eq!(scan.count_no_beacon_positions(9..=11), [25, 26, 28]);
+        assert_eq!(scan.count_no_beacon_positions(-100..=-100), [0]);
+    }
+
+    #[test]
+    fn test_get_perimeter() {
+        let perimeter: HashSet<Point> = get_perimeter(Point(1, 1), 2).collect();
+
+        assert_eq!(perimeter.len(), 8);
+        assert!(perimeter
+            .iter()
+            .all(|point| point.manhattan_distance(Point(1, 1)) == 2));
+        assert!(get_perimeter(Point(1, 1), 0).next().is_none());
+    }
+
+    #[test]
+    fn test_get_row_coverage() {
+        let sensors = [
+            Sensor::new(Point(0, 0), Point(2, 0)),
+            Sensor::new(Point(3, 1), Point(3, 2)),
+            Sensor::new(Point(10, 0), Point(10, 1)),
+        ];
+
+        let coverage = |y| {
+            get_row_coverage(&sensors, y)
+                .intervals()
+                .iter()
+                .map(|interval| (interval.start(), interval.end()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(coverage(0), [(-2, 3), (9, 11)]);
+        assert_eq!(coverage(1), [(-1, 4), (10, 10)]);
+        assert_eq!(coverage(2), [(0, 0), (3, 3)]);
+        assert_eq!(coverage(3), []);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(0, Point(0, 0).manhattan_distance(Point(0, 0)));
+
+        assert_eq!(1, Point(1, 0).manhattan_distance(Point(0, 0)));
+        assert_eq!(1, Point(0, 1).manhattan_distance(Point(0, 0)));
+        assert_eq!(1, Point(0, 0).manhattan_distance(Point(1, 0)));
+        assert_eq!(1, Point(0, 0).manhattan_distance(Point(0, 1)));
+        assert_eq!(1, Point(-1, 0).manhattan_distance(Point(0, 0)));
+        assert_eq!(1, Point(0, -1).manhattan_distance(Point(0, 0)));
+        assert_eq!(1, Point(0, 0).manhattan_distance(Point(-1, 0)));
+        assert_eq!(1, Point(0, 0).manhattan_distance(Point(0, -1)));
+
+        assert_eq!(2, Point(2, 0).manhattan_distance(Point(0, 0)));
+        assert_eq!(2, Point(0, 2).manhattan_distance(Point(0, 0)));
+        assert_eq!(2, Point(0, 0).manhattan_distance(Point(2, 0)));
+        assert_eq!(2, Point(0, 0).manhattan_distance(Point(0, 2)));
+        assert_eq!(2, Point(-2, 0).manhattan_distance(Point(0, 0)));
+        assert_eq!(2, Point(0, -2).manhattan_distance(Point(0, 0)));
+        assert_eq!(2, Point(0, 0).manhattan_distance(Point(-2, 0)));
+        assert_eq!(2, Point(0, 0).manhattan_distance(Point(0, -2)));
+
+        assert_eq!(2, Point(1, 1).manhattan_distance(Point(0, 0)));
+        assert_eq!(2, Point(1, 0).manhattan_distance(Point(0, 1)));
+        assert_eq!(2, Point(0, 1).manhattan_distance(Point(1, 0)));
+        assert_eq!(2, Point(0, 0).manhattan_distance(Point(1, 1)));
+        assert_eq!(2, Point(-1, -1).manhattan_distance(Point(0, 0)));
+        assert_eq!(2, Point(-1, 0).manhattan_distance(Point(0, -1)));
+        assert_eq!(2, Point(0, -1).manhattan_distance(Point(-1, 0)));
+        assert_eq!(2, Point(0, 0).manhattan_distance(Point(-1, -1)));
+    }
+}
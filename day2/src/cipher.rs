@@ -0,0 +1,223 @@
+//! Scores a strategy guide under every meaning its second column could have, since the
+//! elf never explained what the symbols stand for.
+
+use std::cmp::Reverse;
+
+use crate::{
+    game::{Outcome, Shape},
+    Guide,
+};
+
+/// A meaning given to every symbol of the second column along with the total score of
+/// the guide under it
+#[derive(Debug, Eq, PartialEq)]
+pub struct Mapping {
+    /// Every symbol of the second column, in order, with the name of what it stands for
+    pub meanings: Vec<(char, String)>,
+    pub score: usize,
+}
+
+/// Scores the guide with the second column standing for every arrangement of distinct
+/// shapes, from the highest score to the lowest
+pub fn crack_shapes(guide: &Guide) -> Vec<Mapping> {
+    let rules = &guide.rules;
+    let shapes: Vec<Shape> = rules.shapes().collect();
+
+    crack(
+        guide,
+        &shapes,
+        |shape| rules.name(*shape).to_string(),
+        |opponent_shape, shape| rules.get_round_score(*shape, opponent_shape),
+    )
+}
+
+/// Scores the guide with the second column standing for every arrangement of distinct
+/// outcomes, from the highest score to the lowest
+pub fn crack_outcomes(guide: &Guide) -> Vec<Mapping> {
+    let rules = &guide.rules;
+
+    crack(
+        guide,
+        &Outcome::ALL,
+        |outcome| outcome.name().to_string(),
+        |opponent_shape, outcome| {
+            let shape = rules.get_shape(opponent_shape, *outcome);
+            rules.get_shape_score(shape) + rules.get_outcome_score(*outcome)
+        },
+    )
+}
+
+fn crack<T>(
+    guide: &Guide,
+    meanings: &[T],
+    name: impl Fn(&T) -> String,
+    round_score: impl Fn(Shape, &T) -> usize,
+) -> Vec<Mapping> {
+    let mut symbols: Vec<char> = guide.rounds.iter().map(|(_, symbol)| *symbol).collect();
+    symbols.sort();
+    symbols.dedup();
+
+    let mut mappings: Vec<Mapping> = arrangements(symbols.len(), meanings.len())
+        .iter()
+        .map(|arrangement| {
+            let meaning = |symbol: char| {
+                let i = symbols.binary_search(&symbol).unwrap();
+                &meanings[arrangement[i]]
+            };
+            let score = guide
+                .rounds
+                .iter()
+                .filter_map(|(opponent_shape, symbol)| {
+                    let opponent_shape = guide.rules.opponent_shape(*opponent_shape)?;
+                    Some(round_score(opponent_shape, meaning(*symbol)))
+                })
+                .sum();
+
+            Mapping {
+                meanings: symbols
+                    .iter()
+                    .map(|symbol| (*symbol, name(meaning(*symbol))))
+                    .collect(),
+                score,
+            }
+        })
+        .collect();
+    mappings.sort_by_key(|mapping| Reverse(mapping.score));
+
+    mappings
+}
+
+/// Every ordered choice of the passed number of distinct items out of the passed number
+/// of items, given by the items' indices. There are none when there are too few items.
+fn arrangements(length: usize, items: usize) -> Vec<Vec<usize>> {
+    if length == 0 {
+        return vec![vec![]];
+    }
+
+    let mut longer = vec![];
+    for arrangement in arrangements(length - 1, items) {
+        for item in (0..items).filter(|item| !arrangement.contains(item)) {
+            let mut arrangement = arrangement.clone();
+            arrangement.push(item);
+            longer.push(arrangement);
+        }
+    }
+    longer
+}
+
+/// Runs of mappings, sorted by score, that give the same score
+pub fn ties(mappings: &[Mapping]) -> Vec<&[Mapping]> {
+    mappings
+        .chunk_by(|a, b| a.score == b.score)
+        .filter(|run| run.len() > 1)
+        .collect()
+}
+
+/// Renders the score of every mapping to shapes and to outcomes, marking the best and
+/// worst, followed by the scores that several mappings tie on
+pub fn format_cracks(guide: &Guide) -> String {
+    let mut cracks = String::new();
+
+    for (kind, mappings) in [
+        ("shapes", crack_shapes(guide)),
+        ("outcomes", crack_outcomes(guide)),
+    ] {
+        cracks.push_str(&format!("Second column as {}:\n", kind));
+        for (i, mapping) in mappings.iter().enumerate() {
+            let label = match (i == 0, i == mappings.len() - 1) {
+                (true, _) => "best",
+                (_, true) => "worst",
+                _ => "",
+            };
+            cracks.push_str(&format!(
+                "  {:>6} {:<5} {}\n",
+                mapping.score,
+                label,
+                format_meanings(mapping)
+            ));
+        }
+        for run in ties(&mappings) {
+            let meanings: Vec<String> = run.iter().map(format_meanings).collect();
+            cracks.push_str(&format!(
+                "  tied on {}: {}\n",
+                run[0].score,
+                meanings.join("; ")
+            ));
+        }
+    }
+
+    cracks.pop();
+    cracks
+}
+
+fn format_meanings(mapping: &Mapping) -> String {
+    let meanings: Vec<String> = mapping
+        .meanings
+        .iter()
+        .map(|(symbol, name)| format!("{}={}", symbol, name))
+        .collect();
+    meanings.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse_input;
+
+    use crate::{
+        cipher::{arrangements, crack_outcomes, crack_shapes, format_cracks, ties},
+        Day2,
+    };
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_crack_shapes() {
+        let guide = parse_input::<Day2>(EXAMPLE).unwrap();
+        let mappings = crack_shapes(&guide);
+
+        let scores: Vec<usize> = mappings.iter().map(|mapping| mapping.score).collect();
+        assert_eq!(scores, [24, 15, 15, 15, 15, 6]);
+        assert_eq!(
+            mappings[0].meanings,
+            [
+                ('X', "Scissors".to_string()),
+                ('Y', "Paper".to_string()),
+                ('Z', "Rock".to_string())
+            ]
+        );
+
+        let ties = ties(&mappings);
+        assert_eq!(ties.len(), 1);
+        assert_eq!(ties[0].len(), 4);
+        assert!(format_cracks(&guide).contains(
+            "\n  tied on 15: X=Rock Y=Paper Z=Scissors; X=Paper Y=Rock Z=Scissors; \
+             X=Paper Y=Scissors Z=Rock; X=Scissors Y=Rock Z=Paper\n"
+        ));
+    }
+
+    #[test]
+    fn test_crack_outcomes() {
+        let guide = parse_input::<Day2>(EXAMPLE).unwrap();
+        let mappings = crack_outcomes(&guide);
+
+        assert_eq!(mappings.len(), 6);
+        assert!(mappings.iter().any(|mapping| mapping.score == 12
+            && mapping.meanings
+                == [
+                    ('X', "lose".to_string()),
+                    ('Y', "draw".to_string()),
+                    ('Z', "win".to_string())
+                ]));
+        assert!(mappings
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_arrangements() {
+        assert_eq!(arrangements(2, 3).len(), 6);
+        assert_eq!(arrangements(3, 3)[1], [0, 2, 1]);
+        assert_eq!(arrangements(0, 3), [Vec::<usize>::new()]);
+        assert!(arrangements(4, 3).is_empty());
+    }
+}
@@ -0,0 +1,346 @@
+//! Games where the shapes beat each other in a cycle, such as rock paper scissors.
+//! The shapes are listed in an order where every shape beats the half of the other
+//! shapes that come just before it, wrapping around from the first shape to the last.
+
+use aoc_common::{parse_token, ParseError};
+
+const SHAPE_KEYWORD: &str = "shape";
+const OUTCOME_KEYWORD: &str = "outcome";
+const SHAPE_FORM: &str = "`shape <name> <opponent symbol> <symbol> <score>`";
+const OUTCOME_FORM: &str = "`outcome <win|draw|lose> <symbol> <score>`";
+
+/// A shape given by its position in the rules
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Shape(pub usize);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Win, Outcome::Draw, Outcome::Lose];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Win => "win",
+            Outcome::Draw => "draw",
+            Outcome::Lose => "lose",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "win" => Some(Outcome::Win),
+            "draw" => Some(Outcome::Draw),
+            "lose" => Some(Outcome::Lose),
+            _ => None,
+        }
+    }
+}
+
+struct ShapeRule {
+    name: String,
+    /// Symbol of the shape in the opponent's column
+    opponent_symbol: char,
+    /// Symbol of the shape in the second column
+    symbol: char,
+    score: usize,
+}
+
+struct OutcomeRule {
+    outcome: Outcome,
+    /// Symbol of the outcome in the second column
+    symbol: char,
+    score: usize,
+}
+
+/// The shapes of a game along with the symbols and scores of every shape and outcome
+pub struct Rules {
+    shapes: Vec<ShapeRule>,
+    outcomes: Vec<OutcomeRule>,
+}
+
+impl Rules {
+    /// Parses a rule on every line, which is either a shape such as `shape Rock A X 1`
+    /// or an outcome such as `outcome win Z 6`. Shapes are listed in the order they
+    /// beat each other, there must be an odd number of them so no two shapes are tied
+    /// and every outcome needs a rule.
+    pub fn parse(definition: &str) -> Result<Rules, ParseError> {
+        let mut shapes: Vec<ShapeRule> = vec![];
+        let mut outcomes: Vec<OutcomeRule> = vec![];
+        let mut last_line = (0, "");
+
+        for (line_index, line) in definition.lines().enumerate() {
+            last_line = (line_index, line);
+            let tokens: Vec<&str> = line.split(' ').collect();
+            match tokens[..] {
+                [SHAPE_KEYWORD, name, opponent_symbol, symbol, score] => {
+                    let opponent_symbol = parse_symbol(line_index, line, opponent_symbol)?;
+                    let symbol = parse_symbol(line_index, line, symbol)?;
+                    if shapes.iter().any(|shape| {
+                        shape.opponent_symbol == opponent_symbol || shape.symbol == symbol
+                    }) {
+                        return Err(ParseError::new(
+                            line_index,
+                            line,
+                            line,
+                            "a shape with symbols no other shape uses",
+                        ));
+                    }
+                    shapes.push(ShapeRule {
+                        name: name.to_string(),
+                        opponent_symbol,
+                        symbol,
+                        score: parse_token(line_index, line, score, "a score")?,
+                    });
+                }
+                [OUTCOME_KEYWORD, name, symbol, score] => {
+                    let outcome = match Outcome::from_name(name) {
+                        Some(outcome) => outcome,
+                        None => {
+                            return Err(ParseError::new(
+                                line_index,
+                                line,
+                                name,
+                                "`win`, `draw` or `lose`",
+                            ))
+                        }
+                    };
+                    let symbol = parse_symbol(line_index, line, symbol)?;
+                    if outcomes
+                        .iter()
+                        .any(|rule| rule.outcome == outcome || rule.symbol == symbol)
+                    {
+                        return Err(ParseError::new(
+                            line_index,
+                            line,
+                            line,
+                            "an outcome with a symbol no other outcome uses",
+                        ));
+                    }
+                    outcomes.push(OutcomeRule {
+                        outcome,
+                        symbol,
+                        score: parse_token(line_index, line, score, "a score")?,
+                    });
+                }
+                [OUTCOME_KEYWORD, ..] => {
+                    return Err(ParseError::new(line_index, line, line, OUTCOME_FORM))
+                }
+                _ => return Err(ParseError::new(line_index, line, line, SHAPE_FORM)),
+            }
+        }
+
+        let (line_index, line) = last_line;
+        if shapes.len().is_multiple_of(2) {
+            return Err(ParseError::end_of_line(
+                line_index,
+                line,
+                "an odd number of shapes",
+            ));
+        }
+        if outcomes.len() < Outcome::ALL.len() {
+            return Err(ParseError::end_of_line(
+                line_index,
+                line,
+                "a rule for every outcome",
+            ));
+        }
+
+        Ok(Rules { shapes, outcomes })
+    }
+
+    /// Every shape in the order of the rules
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.shapes.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0].name
+    }
+
+    /// The shape with the passed name
+    pub fn named_shape(&self, name: &str) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.name == name)
+            .map(Shape)
+    }
+
+    /// The shape with the passed symbol in the opponent's column
+    pub fn opponent_shape(&self, symbol: char) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.opponent_symbol == symbol)
+            .map(Shape)
+    }
+
+    /// The shape with the passed symbol in the second column
+    pub fn shape(&self, symbol: char) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.symbol == symbol)
+            .map(Shape)
+    }
+
+    /// The outcome with the passed symbol in the second column
+    pub fn outcome(&self, symbol: char) -> Option<Outcome> {
+        self.outcomes
+            .iter()
+            .find(|rule| rule.symbol == symbol)
+            .map(|rule| rule.outcome)
+    }
+
+    /// Symbols of every shape in the opponent's column
+    pub fn opponent_symbols(&self) -> Vec<char> {
+        self.shapes
+            .iter()
+            .map(|shape| shape.opponent_symbol)
+            .collect()
+    }
+
+    /// Symbols of every shape and outcome in the second column
+    pub fn symbols(&self) -> Vec<char> {
+        let mut symbols: Vec<char> = self.shapes.iter().map(|shape| shape.symbol).collect();
+        for rule in &self.outcomes {
+            if !symbols.contains(&rule.symbol) {
+                symbols.push(rule.symbol);
+            }
+        }
+        symbols
+    }
+
+    /// The outcome for the player of the first shape. A shape beats the shapes up to
+    /// half of the way around the cycle before it.
+    pub fn get_outcome(&self, shape: Shape, opponent_shape: Shape) -> Outcome {
+        let count = self.shapes.len();
+        match (shape.0 + count - opponent_shape.0) % count {
+            0 => Outcome::Draw,
+            distance if distance <= count / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// The shape giving the passed outcome against the passed shape. When several shapes
+    /// give the outcome the one with the highest score is chosen.
+    pub fn get_shape(&self, opponent_shape: Shape, outcome: Outcome) -> Shape {
+        self.shapes()
+            .filter(|shape| self.get_outcome(*shape, opponent_shape) == outcome)
+            .max_by_key(|shape| self.get_shape_score(*shape))
+            .unwrap_or(opponent_shape)
+    }
+
+    pub fn get_shape_score(&self, shape: Shape) -> usize {
+        self.shapes[shape.0].score
+    }
+
+    pub fn get_outcome_score(&self, outcome: Outcome) -> usize {
+        self.outcomes
+            .iter()
+            .find(|rule| rule.outcome == outcome)
+            .map_or(0, |rule| rule.score)
+    }
+
+    /// Score of a round played with the first shape against the second
+    pub fn get_round_score(&self, shape: Shape, opponent_shape: Shape) -> usize {
+        self.get_shape_score(shape)
+            + self.get_outcome_score(self.get_outcome(shape, opponent_shape))
+    }
+}
+
+fn parse_symbol(line_index: usize, line: &str, symbol: &str) -> Result<char, ParseError> {
+    let mut chars = symbol.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(ParseError::new(
+            line_index,
+            line,
+            symbol,
+            "a single character symbol",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::{Outcome, Rules, Shape},
+        ROCK_PAPER_SCISSORS, ROCK_PAPER_SCISSORS_LIZARD_SPOCK,
+    };
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let rules = Rules::parse(ROCK_PAPER_SCISSORS).unwrap();
+        let rock = rules.opponent_shape('A').unwrap();
+        let paper = rules.shape('Y').unwrap();
+        let scissors = rules.shape('Z').unwrap();
+
+        assert_eq!(rules.get_outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.get_outcome(rock, paper), Outcome::Lose);
+        assert_eq!(rules.get_outcome(rock, scissors), Outcome::Win);
+        assert_eq!(rules.get_outcome(scissors, scissors), Outcome::Draw);
+        assert_eq!(rules.get_shape(scissors, Outcome::Win), rock);
+        assert_eq!(rules.get_shape(rock, Outcome::Lose), scissors);
+        assert_eq!(rules.get_round_score(paper, rock), 8);
+        assert_eq!(rules.outcome('X'), Some(Outcome::Lose));
+        assert_eq!(rules.name(scissors), "Scissors");
+    }
+
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock() {
+        let rules = Rules::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).unwrap();
+        let shape = |name| rules.named_shape(name).unwrap();
+        let beats = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+
+        for (winner, loser) in beats {
+            assert_eq!(rules.get_outcome(shape(winner), shape(loser)), Outcome::Win);
+            assert_eq!(
+                rules.get_outcome(shape(loser), shape(winner)),
+                Outcome::Lose
+            );
+        }
+        assert_eq!(rules.get_shape(shape("Rock"), Outcome::Win), shape("Paper"));
+        assert_eq!(rules.get_shape(Shape(0), Outcome::Draw), Shape(0));
+        assert_eq!(rules.named_shape("Dynamite"), None);
+    }
+
+    #[test]
+    fn test_rules_parse() {
+        let error = Rules::parse("shape Rock A X 1\nshape Paper B Y 2\noutcome win Z 6")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (3, "an odd number of shapes")
+        );
+
+        let error = Rules::parse("shape Rock A X 1\noutcome win Z 6")
+            .err()
+            .unwrap();
+        assert_eq!(error.expected, "a rule for every outcome");
+
+        let error = Rules::parse("shape Rock A X 1\nshape Paper A Y 2")
+            .err()
+            .unwrap();
+        assert_eq!(error.line, 2);
+
+        let error = Rules::parse("outcome tie Y 3").err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (9, "tie"));
+        assert!(Rules::parse("shape Rock AA X 1").is_err());
+        assert!(Rules::parse("rock A X 1").is_err());
+    }
+}
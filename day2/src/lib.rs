@@ -0,0 +1,196 @@
+mod cipher;
+mod game;
+mod simulation;
+mod tournament;
+
+pub use cipher::{crack_outcomes, crack_shapes, format_cracks, ties, Mapping};
+pub use game::{Outcome, Rules, Shape};
+pub use simulation::{
+    format_simulations, simulate, FrequencyPredictor, MarkovPredictor, Predictor, Simulation,
+};
+pub use tournament::{format_league_table, play_tournament, Entrant, Standing, Strategy};
+
+use aoc_common::{input_file, Answer, ParseError, Solver};
+
+pub const FILE_NAME: &str = input_file!();
+/// The rules of the puzzle, used by inputs that do not start with their own rules
+pub const ROCK_PAPER_SCISSORS: &str = include_str!("rock_paper_scissors.txt");
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str =
+    include_str!("rock_paper_scissors_lizard_spock.txt");
+const RULE_PREFIXES: [&str; 2] = ["shape ", "outcome "];
+const OPPONENT_SHAPE_INDEX: usize = 0;
+const SEPARATOR_INDEX: usize = 1;
+const SHAPE_INDEX: usize = 2;
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Input = Guide;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Guide::new(data)
+    }
+
+    fn part_one(guide: &Self::Input) -> Answer {
+        let rules = &guide.rules;
+        let mut total_score = 0;
+
+        for (opponent_shape, shape) in &guide.rounds {
+            let (opponent_shape, shape) =
+                match (rules.opponent_shape(*opponent_shape), rules.shape(*shape)) {
+                    (Some(opponent_shape), Some(shape)) => (opponent_shape, shape),
+                    _ => return Answer::Unsolved,
+                };
+
+            total_score += rules.get_round_score(shape, opponent_shape);
+        }
+
+        total_score.into()
+    }
+
+    fn part_two(guide: &Self::Input) -> Answer {
+        let rules = &guide.rules;
+        let mut total_score = 0;
+
+        for (opponent_shape, outcome) in &guide.rounds {
+            let (opponent_shape, outcome) = match (
+                rules.opponent_shape(*opponent_shape),
+                rules.outcome(*outcome),
+            ) {
+                (Some(opponent_shape), Some(outcome)) => (opponent_shape, outcome),
+                _ => return Answer::Unsolved,
+            };
+
+            let shape = rules.get_shape(opponent_shape, outcome);
+            total_score += rules.get_outcome_score(outcome);
+            total_score += rules.get_shape_score(shape);
+        }
+
+        total_score.into()
+    }
+}
+
+/// The strategy guide along with the rules of the game it is played with
+pub struct Guide {
+    pub rules: Rules,
+    /// The opponent's shape column and second column of every round
+    pub rounds: Vec<(char, char)>,
+}
+
+impl Guide {
+    /// Parses the rounds of the guide, one per line. The guide may start with its own
+    /// rules, in the form read by `Rules::parse`, and otherwise uses rock paper scissors.
+    fn new(data: &str) -> Result<Guide, ParseError> {
+        let rules_end: usize = data
+            .split_inclusive('\n')
+            .take_while(|line| RULE_PREFIXES.iter().any(|prefix| line.starts_with(prefix)))
+            .map(str::len)
+            .sum();
+        let rule_count = data[..rules_end].lines().count();
+        let rules = match rule_count {
+            0 => Rules::parse(ROCK_PAPER_SCISSORS).expect("the default rules are valid"),
+            _ => Rules::parse(&data[..rules_end])?,
+        };
+
+        let rounds = data
+            .lines()
+            .enumerate()
+            .skip(rule_count)
+            .map(|(i, line)| parse_round(i, line, &rules))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Guide { rules, rounds })
+    }
+}
+
+/// Parses a round such as `A Y` into the opponent's shape column and second column
+fn parse_round(line_index: usize, line: &str, rules: &Rules) -> Result<(char, char), ParseError> {
+    let opponent_symbols = rules.opponent_symbols();
+    let opponent_shape = parse_column(
+        line_index,
+        line,
+        OPPONENT_SHAPE_INDEX,
+        &opponent_symbols,
+        &format!("an opponent shape {}", format_symbols(&opponent_symbols)),
+    )?;
+    parse_column(line_index, line, SEPARATOR_INDEX, &[' '], "a space")?;
+    let symbols = rules.symbols();
+    let shape = parse_column(
+        line_index,
+        line,
+        SHAPE_INDEX,
+        &symbols,
+        &format_symbols(&symbols),
+    )?;
+
+    match line.char_indices().nth(SHAPE_INDEX + 1) {
+        Some((i, _)) => Err(ParseError::new(line_index, line, &line[i..], "end of line")),
+        None => Ok((opponent_shape, shape)),
+    }
+}
+
+/// Lists the passed symbols such as `` `A`, `B` or `C` ``
+fn format_symbols(symbols: &[char]) -> String {
+    let symbols: Vec<String> = symbols.iter().map(|c| format!("`{}`", c)).collect();
+    match symbols.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        _ => symbols.concat(),
+    }
+}
+
+/// Returns the char at the passed index if it is one of the passed symbols
+fn parse_column(
+    line_index: usize,
+    line: &str,
+    index: usize,
+    symbols: &[char],
+    expected: &str,
+) -> Result<char, ParseError> {
+    match line.char_indices().nth(index) {
+        Some((_, c)) if symbols.contains(&c) => Ok(c),
+        Some((i, c)) => Err(ParseError::new(
+            line_index,
+            line,
+            &line[i..i + c.len_utf8()],
+            expected,
+        )),
+        None => Err(ParseError::end_of_line(line_index, line, expected)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse_input, Answer, Solver};
+
+    use crate::{format_symbols, Day2, ROCK_PAPER_SCISSORS_LIZARD_SPOCK};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day2>(EXAMPLE).unwrap();
+        assert_eq!(Day2::part_one(&input), Answer::from(15));
+        assert_eq!(Day2::part_two(&input), Answer::from(12));
+    }
+
+    #[test]
+    fn test_rules_in_input() {
+        let data = format!("{}A V\nC Z\nE Y", ROCK_PAPER_SCISSORS_LIZARD_SPOCK);
+        let input = parse_input::<Day2>(&data).unwrap();
+        assert_eq!(input.rounds.len(), 3);
+        // rock draws rock, scissors beat paper and lizard loses to scissors
+        assert_eq!(Day2::part_one(&input), Answer::from(1 + 3 + 5 + 6 + 4));
+        assert_eq!(Day2::part_two(&input), Answer::Unsolved);
+
+        let error = parse_input::<Day2>(&format!("{}F X", ROCK_PAPER_SCISSORS_LIZARD_SPOCK))
+            .err()
+            .unwrap();
+        assert_eq!(error.line, 9);
+        assert_eq!(
+            error.expected,
+            "an opponent shape `A`, `B`, `C`, `D` or `E`"
+        );
+        assert!(parse_input::<Day2>("shape Rock A X 1\nA X").is_err());
+        assert_eq!(format_symbols(&['X']), "`X`");
+    }
+}
@@ -1,129 +1,59 @@
-use std::fs;
-
-const FILE_NAME: &str = "data1.txt";
-const SHAPE_INDEX: usize = 2;
+use aoc_common::{parse_input, read_input_or_exit, solve};
+use day2::{
+    format_cracks, format_league_table, format_simulations, play_tournament, Day2, Entrant,
+    FILE_NAME,
+};
+use std::{env, process};
+
+/// Also scores the guide under every meaning of its second column
+const CRACK_FLAG: &str = "--crack";
+/// Also plays against the opponent by predicting their shapes instead of using the guide
+const SIMULATE_FLAG: &str = "--simulate";
+/// Also plays a tournament between the guide, the opponent, the bots and the guides in
+/// every other passed file
+const TOURNAMENT_FLAG: &str = "--tournament";
 
 fn main() {
-    let mut data = fs::read_to_string(FILE_NAME).expect("Something went wrong reading the file");
-    data.pop();
-
-    part_one(&data);
-    part_two(&data);
-}
-
-fn part_one(data: &str) {
-    let mut total_score = 0;
-
-    for line in data.lines() {
-        let opponent_shape = line.chars().next().unwrap();
-        let shape = line.chars().nth(SHAPE_INDEX).unwrap();
-
-        let opponent_shape = Shape::from_char_to_shape(opponent_shape);
-        let shape = Shape::from_char_to_shape(shape);
-
-        let outcome = get_outcome(&shape, &opponent_shape);
-        total_score += get_outcome_score(&outcome);
-        total_score += get_shape_score(&shape);
-    }
-
-    println!("Part one: {}", total_score);
-}
-
-fn part_two(data: &str) {
-    let mut total_score = 0;
-
-    for line in data.lines() {
-        let opponent_shape = line.chars().next().unwrap();
-        let shape = line.chars().nth(SHAPE_INDEX).unwrap();
-
-        let opponent_shape = Shape::from_char_to_shape(opponent_shape);
-        let outcome = Shape::from_char_to_outcome(shape);
-
-        let shape = get_shape(&opponent_shape, &outcome);
-        total_score += get_outcome_score(&outcome);
-        total_score += get_shape_score(&shape);
-    }
-
-    println!("Part two: {}", total_score);
-}
-
-enum Shape {
-    Rock,
-    Paper,
-    Scissor,
-}
+    let data = read_input_or_exit(FILE_NAME);
+    let args: Vec<String> = env::args().skip(1).collect();
 
-impl Shape {
-    fn from_char_to_shape(value: char) -> Shape {
-        match value {
-            'A' => Shape::Rock,
-            'B' => Shape::Paper,
-            'C' => Shape::Scissor,
-            'X' => Shape::Rock,
-            'Y' => Shape::Paper,
-            'Z' => Shape::Scissor,
-            _ => panic!("Unknown value: {}", value),
+    if let Ok(guide) = parse_input::<Day2>(&data) {
+        if args.iter().any(|arg| arg == CRACK_FLAG) {
+            println!("{}", format_cracks(&guide));
         }
-    }
-
-    fn from_char_to_outcome(value: char) -> Outcome {
-        match value {
-            'X' => Outcome::Lose,
-            'Y' => Outcome::Draw,
-            'Z' => Outcome::Win,
-            _ => panic!("Unknown value: {}", value),
+        if args.iter().any(|arg| arg == SIMULATE_FLAG) {
+            println!("{}", format_simulations(&guide));
+        }
+        if args.iter().any(|arg| arg == TOURNAMENT_FLAG) {
+            let mut entrants = vec![
+                Entrant::from_guide("guide", &guide),
+                Entrant::from_opponent("opponent", &guide),
+            ];
+            for file_name in args.iter().filter(|arg| !arg.starts_with("--")) {
+                let data = read_input_or_exit(file_name);
+                let other_guide = match parse_input::<Day2>(&data) {
+                    Ok(other_guide) => other_guide,
+                    Err(error) => {
+                        eprintln!("{}", error.diagnostic(file_name, &data));
+                        process::exit(1);
+                    }
+                };
+                match Entrant::from_other_guide(file_name, &other_guide, &guide.rules) {
+                    Ok(entrant) => entrants.push(entrant),
+                    Err(shape) => {
+                        eprintln!(
+                            "error: {}: the shape {} is not in the rules of the tournament",
+                            file_name, shape
+                        );
+                        process::exit(1);
+                    }
+                }
+            }
+            entrants.extend(Entrant::bots(&guide.rules));
+
+            let standings = play_tournament(&guide.rules, &entrants, guide.rounds.len());
+            println!("{}", format_league_table(&standings));
         }
     }
-}
-
-enum Outcome {
-    Win,
-    Draw,
-    Lose,
-}
-
-fn get_outcome(shape: &Shape, opponent_shape: &Shape) -> Outcome {
-    let shapes = (shape, opponent_shape);
-    match shapes {
-        (Shape::Rock, Shape::Rock) => Outcome::Draw,
-        (Shape::Rock, Shape::Paper) => Outcome::Lose,
-        (Shape::Rock, Shape::Scissor) => Outcome::Win,
-        (Shape::Paper, Shape::Rock) => Outcome::Win,
-        (Shape::Paper, Shape::Paper) => Outcome::Draw,
-        (Shape::Paper, Shape::Scissor) => Outcome::Lose,
-        (Shape::Scissor, Shape::Rock) => Outcome::Lose,
-        (Shape::Scissor, Shape::Paper) => Outcome::Win,
-        (Shape::Scissor, Shape::Scissor) => Outcome::Draw,
-    }
-}
-
-fn get_shape(opponent_shape: &Shape, outcome: &Outcome) -> Shape {
-    let scenarios = (opponent_shape, outcome);
-    match scenarios {
-        (Shape::Rock, Outcome::Win) => Shape::Paper,
-        (Shape::Rock, Outcome::Draw) => Shape::Rock,
-        (Shape::Rock, Outcome::Lose) => Shape::Scissor,
-        (Shape::Paper, Outcome::Win) => Shape::Scissor,
-        (Shape::Paper, Outcome::Draw) => Shape::Paper,
-        (Shape::Paper, Outcome::Lose) => Shape::Rock,
-        (Shape::Scissor, Outcome::Win) => Shape::Rock,
-        (Shape::Scissor, Outcome::Draw) => Shape::Scissor,
-        (Shape::Scissor, Outcome::Lose) => Shape::Paper,
-    }
-}
-
-fn get_shape_score(shape: &Shape) -> usize {
-    match shape {
-        Shape::Rock => 1,
-        Shape::Paper => 2,
-        Shape::Scissor => 3,
-    }
-}
-
-fn get_outcome_score(outcome: &Outcome) -> usize {
-    match outcome {
-        Outcome::Win => 6,
-        Outcome::Draw => 3,
-        Outcome::Lose => 0,
-    }
+    solve::<Day2>(FILE_NAME, &data);
 }
@@ -0,0 +1,237 @@
+//! Plays against the opponent's recorded shapes without the guide, predicting every
+//! shape the opponent plays from the shapes they played before it and playing the
+//! shape that beats the prediction.
+
+use crate::{
+    game::{Outcome, Rules, Shape},
+    Guide,
+};
+
+/// Predicts the opponent's next shape, learning from every shape they play
+pub trait Predictor {
+    /// The shape the opponent is expected to play next, if anything has been learned
+    fn predict(&self) -> Option<Shape>;
+
+    /// Learns from the shape the opponent played
+    fn observe(&mut self, shape: Shape);
+}
+
+/// Predicts the shape the opponent has played most often
+pub struct FrequencyPredictor {
+    /// Number of times the opponent played every shape
+    counts: Vec<usize>,
+}
+
+impl FrequencyPredictor {
+    pub fn new(rules: &Rules) -> FrequencyPredictor {
+        FrequencyPredictor {
+            counts: vec![0; rules.shapes().count()],
+        }
+    }
+}
+
+impl Predictor for FrequencyPredictor {
+    fn predict(&self) -> Option<Shape> {
+        most_common(&self.counts)
+    }
+
+    fn observe(&mut self, shape: Shape) {
+        self.counts[shape.0] += 1;
+    }
+}
+
+/// Predicts the shape the opponent has most often played after the shape they played
+/// last, as a first order Markov chain
+pub struct MarkovPredictor {
+    last: Option<Shape>,
+    /// Number of times the opponent played every shape after every shape
+    transitions: Vec<Vec<usize>>,
+}
+
+impl MarkovPredictor {
+    pub fn new(rules: &Rules) -> MarkovPredictor {
+        let count = rules.shapes().count();
+        MarkovPredictor {
+            last: None,
+            transitions: vec![vec![0; count]; count],
+        }
+    }
+}
+
+impl Predictor for MarkovPredictor {
+    fn predict(&self) -> Option<Shape> {
+        most_common(&self.transitions[self.last?.0])
+    }
+
+    fn observe(&mut self, shape: Shape) {
+        if let Some(last) = self.last {
+            self.transitions[last.0][shape.0] += 1;
+        }
+        self.last = Some(shape);
+    }
+}
+
+/// The shape counted most often, preferring the first shape of the rules on a tie, if
+/// any shape has been counted
+fn most_common(counts: &[usize]) -> Option<Shape> {
+    let (shape, count) = counts
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, count)| **count)?;
+    match *count > 0 {
+        true => Some(Shape(shape)),
+        false => None,
+    }
+}
+
+/// How a player predicting the opponent fared over every round of the guide
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Simulation {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    /// Number of rounds where the opponent played the predicted shape
+    pub correct_predictions: usize,
+    /// Shape and outcome score of every round added together
+    pub score: usize,
+}
+
+impl Simulation {
+    pub fn rounds(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    /// Share of the rounds that were won, from 0 to 1
+    pub fn win_rate(&self) -> f64 {
+        match self.rounds() {
+            0 => 0.0,
+            rounds => self.wins as f64 / rounds as f64,
+        }
+    }
+}
+
+/// Plays every round of the guide against the opponent's recorded shape with the shape
+/// that beats the passed predictor's prediction. The first shape of the rules is played
+/// while there is no prediction.
+pub fn simulate(guide: &Guide, mut predictor: impl Predictor) -> Simulation {
+    let rules = &guide.rules;
+    let mut simulation = Simulation::default();
+
+    for opponent_shape in guide
+        .rounds
+        .iter()
+        .filter_map(|(opponent_shape, _)| rules.opponent_shape(*opponent_shape))
+    {
+        let prediction = predictor.predict();
+        let shape = match prediction {
+            Some(prediction) => rules.get_shape(prediction, Outcome::Win),
+            None => Shape(0),
+        };
+
+        let outcome = rules.get_outcome(shape, opponent_shape);
+        match outcome {
+            Outcome::Win => simulation.wins += 1,
+            Outcome::Draw => simulation.draws += 1,
+            Outcome::Lose => simulation.losses += 1,
+        }
+        if prediction == Some(opponent_shape) {
+            simulation.correct_predictions += 1;
+        }
+        simulation.score += rules.get_shape_score(shape) + rules.get_outcome_score(outcome);
+
+        predictor.observe(opponent_shape);
+    }
+
+    simulation
+}
+
+/// Renders how a player using every predictor fared against the opponent
+pub fn format_simulations(guide: &Guide) -> String {
+    let simulations = [
+        (
+            "frequency",
+            simulate(guide, FrequencyPredictor::new(&guide.rules)),
+        ),
+        (
+            "markov",
+            simulate(guide, MarkovPredictor::new(&guide.rules)),
+        ),
+    ];
+
+    let mut table = format!(
+        "{:<10} {:>6} {:>6} {:>6} {:>9} {:>8} {:>8}\n",
+        "Predictor", "Wins", "Draws", "Losses", "Win rate", "Correct", "Score"
+    );
+    for (name, simulation) in simulations {
+        table.push_str(&format!(
+            "{:<10} {:>6} {:>6} {:>6} {:>8.1}% {:>8} {:>8}\n",
+            name,
+            simulation.wins,
+            simulation.draws,
+            simulation.losses,
+            simulation.win_rate() * 100.0,
+            simulation.correct_predictions,
+            simulation.score,
+        ));
+    }
+    table.pop();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse_input;
+
+    use crate::{
+        game::Shape,
+        simulation::{simulate, FrequencyPredictor, MarkovPredictor, Predictor, Simulation},
+        Day2,
+    };
+
+    #[test]
+    fn test_predictors() {
+        let guide = parse_input::<Day2>("A X").unwrap();
+
+        let mut frequency = FrequencyPredictor::new(&guide.rules);
+        assert_eq!(frequency.predict(), None);
+        for shape in [2, 1, 2, 1] {
+            frequency.observe(Shape(shape));
+        }
+        assert_eq!(frequency.predict(), Some(Shape(1)));
+        frequency.observe(Shape(2));
+        assert_eq!(frequency.predict(), Some(Shape(2)));
+
+        let mut markov = MarkovPredictor::new(&guide.rules);
+        for shape in [0, 1, 0, 1, 2, 0] {
+            markov.observe(Shape(shape));
+        }
+        assert_eq!(markov.predict(), Some(Shape(1)));
+        markov.observe(Shape(2));
+        assert_eq!(markov.predict(), Some(Shape(0)));
+    }
+
+    #[test]
+    fn test_simulate() {
+        // the opponent repeats rock, paper, scissors
+        let guide = parse_input::<Day2>("A X\nB X\nC X\nA X\nB X\nC X\nA X").unwrap();
+
+        // rock is played without a prediction in the first round, after which rock is
+        // always predicted as it is never played less often than the other shapes
+        assert_eq!(
+            simulate(&guide, FrequencyPredictor::new(&guide.rules)),
+            Simulation {
+                wins: 2,
+                draws: 3,
+                losses: 2,
+                correct_predictions: 2,
+                score: 34,
+            }
+        );
+
+        let markov = simulate(&guide, MarkovPredictor::new(&guide.rules));
+        assert_eq!(markov.correct_predictions, 3);
+        assert_eq!(markov.wins, 4);
+        assert!((markov.win_rate() - 4.0 / 7.0).abs() < 1e-9);
+    }
+}
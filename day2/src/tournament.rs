@@ -0,0 +1,290 @@
+//! A round robin tournament where every entrant plays every other entrant over the same
+//! number of rounds, ranked in a league table by their total score.
+
+use std::cmp::{Ordering, Reverse};
+
+use crate::{
+    game::{Outcome, Rules, Shape},
+    Guide,
+};
+
+const ENTRANT_HEADING: &str = "Entrant";
+
+/// How an entrant chooses the shape to play every round
+pub enum Strategy {
+    /// Plays the same shape every round
+    Always(Shape),
+    /// Plays the shape the other entrant played last round
+    CopyLast,
+    /// Plays the shape that beats the shape the other entrant played last round
+    BeatLast,
+    /// Plays the shapes in order, starting over once every shape has been played
+    Fixed(Vec<Shape>),
+}
+
+impl Strategy {
+    /// The shape to play in the round after the passed shapes of the other entrant.
+    /// Strategies reacting to the other entrant play the first shape in the first round.
+    fn next_shape(&self, rules: &Rules, other_shapes: &[Shape]) -> Shape {
+        match self {
+            Strategy::Always(shape) => *shape,
+            Strategy::CopyLast => other_shapes.last().copied().unwrap_or(Shape(0)),
+            Strategy::BeatLast => match other_shapes.last() {
+                Some(last) => rules.get_shape(*last, Outcome::Win),
+                None => Shape(0),
+            },
+            Strategy::Fixed(shapes) => match shapes.is_empty() {
+                true => Shape(0),
+                false => shapes[other_shapes.len() % shapes.len()],
+            },
+        }
+    }
+}
+
+pub struct Entrant {
+    pub name: String,
+    pub strategy: Strategy,
+}
+
+impl Entrant {
+    pub fn new(name: &str, strategy: Strategy) -> Entrant {
+        Entrant {
+            name: name.to_string(),
+            strategy,
+        }
+    }
+
+    /// Plays the second column of the passed guide read as shapes
+    pub fn from_guide(name: &str, guide: &Guide) -> Entrant {
+        let shapes = guide
+            .rounds
+            .iter()
+            .filter_map(|(_, shape)| guide.rules.shape(*shape))
+            .collect();
+        Entrant::new(name, Strategy::Fixed(shapes))
+    }
+
+    /// Plays the second column of the passed guide against entrants using the passed
+    /// rules, matching the guide's shapes to the rules' shapes by name. Returns the name
+    /// of the first shape of the guide the rules do not have, if there is one.
+    pub fn from_other_guide(name: &str, guide: &Guide, rules: &Rules) -> Result<Entrant, String> {
+        let shapes = guide
+            .rounds
+            .iter()
+            .filter_map(|(_, shape)| guide.rules.shape(*shape))
+            .map(|shape| {
+                let shape_name = guide.rules.name(shape);
+                rules
+                    .named_shape(shape_name)
+                    .ok_or_else(|| shape_name.to_string())
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Entrant::new(name, Strategy::Fixed(shapes)))
+    }
+
+    /// Plays the opponent's column of the passed guide
+    pub fn from_opponent(name: &str, guide: &Guide) -> Entrant {
+        let shapes = guide
+            .rounds
+            .iter()
+            .filter_map(|(opponent_shape, _)| guide.rules.opponent_shape(*opponent_shape))
+            .collect();
+        Entrant::new(name, Strategy::Fixed(shapes))
+    }
+
+    /// The entrants that always play one shape, copy the last shape or beat the last
+    /// shape of the other entrant
+    pub fn bots(rules: &Rules) -> Vec<Entrant> {
+        let mut bots: Vec<Entrant> = rules
+            .shapes()
+            .map(|shape| {
+                let name = format!("always {}", rules.name(shape).to_lowercase());
+                Entrant::new(&name, Strategy::Always(shape))
+            })
+            .collect();
+        bots.push(Entrant::new("copy last", Strategy::CopyLast));
+        bots.push(Entrant::new("beat last", Strategy::BeatLast));
+        bots
+    }
+}
+
+/// How an entrant fared over every match of the tournament
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    /// Matches where the entrant scored more than the other entrant
+    pub won: usize,
+    pub drawn: usize,
+    pub lost: usize,
+    /// Shape and outcome score of every round of every match added together
+    pub score: usize,
+}
+
+impl Standing {
+    /// Adds a match where the entrant scored the first score and the other entrant the second
+    fn record(&mut self, score: usize, other_score: usize) {
+        self.score += score;
+        match score.cmp(&other_score) {
+            Ordering::Greater => self.won += 1,
+            Ordering::Equal => self.drawn += 1,
+            Ordering::Less => self.lost += 1,
+        }
+    }
+}
+
+/// Plays the passed number of rounds between the two entrants and returns their scores
+fn play_match(rules: &Rules, first: &Entrant, second: &Entrant, rounds: usize) -> [usize; 2] {
+    let mut first_shapes = vec![];
+    let mut second_shapes = vec![];
+    let mut scores = [0, 0];
+
+    for _ in 0..rounds {
+        let first_shape = first.strategy.next_shape(rules, &second_shapes);
+        let second_shape = second.strategy.next_shape(rules, &first_shapes);
+
+        scores[0] += rules.get_shape_score(first_shape)
+            + rules.get_outcome_score(rules.get_outcome(first_shape, second_shape));
+        scores[1] += rules.get_shape_score(second_shape)
+            + rules.get_outcome_score(rules.get_outcome(second_shape, first_shape));
+
+        first_shapes.push(first_shape);
+        second_shapes.push(second_shape);
+    }
+
+    scores
+}
+
+/// Plays a match of the passed number of rounds between every pair of entrants and
+/// returns the league table, from the highest total score to the lowest
+pub fn play_tournament(rules: &Rules, entrants: &[Entrant], rounds: usize) -> Vec<Standing> {
+    let mut standings: Vec<Standing> = entrants
+        .iter()
+        .map(|entrant| Standing {
+            name: entrant.name.clone(),
+            ..Standing::default()
+        })
+        .collect();
+
+    for i in 0..entrants.len() {
+        for j in i + 1..entrants.len() {
+            let [score, other_score] = play_match(rules, &entrants[i], &entrants[j], rounds);
+            standings[i].record(score, other_score);
+            standings[j].record(other_score, score);
+        }
+    }
+
+    standings.sort_by_key(|standing| Reverse(standing.score));
+    standings
+}
+
+/// Renders the league table with a row for every entrant
+pub fn format_league_table(standings: &[Standing]) -> String {
+    let width = standings
+        .iter()
+        .map(|standing| standing.name.chars().count())
+        .chain([ENTRANT_HEADING.len()])
+        .max()
+        .unwrap_or(0);
+
+    let mut table = format!(
+        "{:<4} {:<width$} {:>4} {:>5} {:>4} {:>8}\n",
+        "Rank", ENTRANT_HEADING, "Won", "Drawn", "Lost", "Score"
+    );
+    for (i, standing) in standings.iter().enumerate() {
+        table.push_str(&format!(
+            "{:<4} {:<width$} {:>4} {:>5} {:>4} {:>8}\n",
+            i + 1,
+            standing.name,
+            standing.won,
+            standing.drawn,
+            standing.lost,
+            standing.score
+        ));
+    }
+    table.pop();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse_input;
+
+    use crate::{
+        game::Shape,
+        tournament::{format_league_table, play_match, play_tournament, Entrant, Strategy},
+        Day2, ROCK_PAPER_SCISSORS_LIZARD_SPOCK,
+    };
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_play_match() {
+        let guide = parse_input::<Day2>(EXAMPLE).unwrap();
+        let rules = &guide.rules;
+        let rock = Entrant::new("rock", Strategy::Always(Shape(0)));
+
+        // rock draws the first round then beat last plays paper every round
+        let beat_last = Entrant::new("beat last", Strategy::BeatLast);
+        assert_eq!(
+            play_match(rules, &rock, &beat_last, 3),
+            [4 + 1 + 1, 4 + 8 + 8]
+        );
+
+        // copying rock always draws
+        let copy_last = Entrant::new("copy last", Strategy::CopyLast);
+        assert_eq!(play_match(rules, &copy_last, &rock, 3), [12, 12]);
+
+        // the guide's shapes against the opponent's column score part one
+        let guide_entrant = Entrant::from_guide("guide", &guide);
+        let opponent = Entrant::from_opponent("opponent", &guide);
+        assert_eq!(play_match(rules, &guide_entrant, &opponent, 3)[0], 15);
+        assert_eq!(play_match(rules, &guide_entrant, &opponent, 6)[0], 30);
+    }
+
+    #[test]
+    fn test_play_tournament() {
+        let guide = parse_input::<Day2>(EXAMPLE).unwrap();
+        let entrants = Entrant::bots(&guide.rules);
+        let standings = play_tournament(&guide.rules, &entrants, 10);
+
+        assert_eq!(standings.len(), 5);
+        for standing in &standings {
+            assert_eq!(standing.won + standing.drawn + standing.lost, 4);
+        }
+        assert!(standings
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+        let table = format_league_table(&standings);
+        assert_eq!(
+            table.lines().next().unwrap(),
+            "Rank Entrant          Won Drawn Lost    Score"
+        );
+        assert_eq!(table.lines().count(), 6);
+        assert!(table
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with(&format!("1    {:<15}", standings[0].name)));
+    }
+
+    #[test]
+    fn test_from_other_guide() {
+        let guide = parse_input::<Day2>(EXAMPLE).unwrap();
+        let other_guide =
+            parse_input::<Day2>(&format!("{}A X\nA Y", ROCK_PAPER_SCISSORS_LIZARD_SPOCK)).unwrap();
+
+        // rock paper scissors lizard spock lists its shapes in another order
+        let other = Entrant::from_other_guide("other", &guide, &other_guide.rules).unwrap();
+        let rock = other_guide.rules.named_shape("Rock").unwrap();
+        let paper = other_guide.rules.named_shape("Paper").unwrap();
+        let scissors = other_guide.rules.named_shape("Scissors").unwrap();
+        match other.strategy {
+            Strategy::Fixed(shapes) => assert_eq!(shapes, [paper, rock, scissors]),
+            _ => panic!("expected a fixed strategy"),
+        }
+
+        let error = Entrant::from_other_guide("other", &other_guide, &guide.rules);
+        // Y is Lizard in the other guide
+        assert_eq!(error.err(), Some("Lizard".to_string()));
+    }
+}
@@ -0,0 +1,323 @@
+use aoc_common::{input_file, Answer, NormalizedInput, ParseError, Solver};
+use std::fmt;
+
+mod validation;
+
+pub use validation::{format_diagnoses, validate_rucksacks, Compartment, Diagnosis, Move, Problem};
+
+pub const FILE_NAME: &str = input_file!();
+/// Number of rucksacks in every group of part two
+pub const GROUP_SIZE: usize = 3;
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Input = Vec<Rucksack>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines()
+            .enumerate()
+            .map(|(i, line)| {
+                if let Some((j, c)) = find_invalid_item(line) {
+                    return Err(ParseError::new(
+                        i,
+                        line,
+                        &line[j..j + c.len_utf8()],
+                        ITEM_FORM,
+                    ));
+                }
+                match line.len().is_multiple_of(2) {
+                    true => Ok(Rucksack::new(line)),
+                    false => Err(ParseError::end_of_line(i, line, "an even number of items")),
+                }
+            })
+            .collect()
+    }
+
+    fn part_one(rucksacks: &Self::Input) -> Answer {
+        let mut total = 0;
+
+        for rucksack in rucksacks {
+            match get_items(rucksack.shared_items())[..] {
+                [item] => total += get_priority(item),
+                _ => return Answer::Unsolved,
+            }
+        }
+
+        total.into()
+    }
+
+    fn part_two(rucksacks: &Self::Input) -> Answer {
+        let mut total = 0;
+
+        for badge in get_badges(rucksacks, GROUP_SIZE) {
+            match badge {
+                Ok(badge) => total += get_priority(badge),
+                Err(_) => return Answer::Unsolved,
+            }
+        }
+
+        total.into()
+    }
+}
+
+const ITEM_FORM: &str = "an item from a to z or A to Z";
+
+/// Every item type, a bit for each of the 52 priorities
+const ALL_ITEMS: u64 = (1 << 52) - 1;
+
+/// The item types in both compartments of a rucksack, where the item of priority `p`
+/// is bit `p - 1` of the mask
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rucksack {
+    first: u64,
+    second: u64,
+}
+
+impl Rucksack {
+    /// Splits the passed items, which must be from a to z or A to Z, into two compartments
+    /// of the same size
+    fn new(items: &str) -> Rucksack {
+        let (first, second) = items.split_at(items.len() / 2);
+        Rucksack {
+            first: get_mask(first),
+            second: get_mask(second),
+        }
+    }
+
+    /// The item types in either compartment
+    pub fn items(self) -> u64 {
+        self.first | self.second
+    }
+
+    /// The item types in both compartments
+    pub fn shared_items(self) -> u64 {
+        self.first & self.second
+    }
+}
+
+/// The rucksacks of a group of elves, which should all carry a single item type in common
+#[derive(Debug, Eq, PartialEq)]
+pub struct Group {
+    /// Line of the first rucksack of the group in the input, starting from 1
+    pub first_line: usize,
+    /// Line of the last rucksack of the group in the input, starting from 1
+    pub last_line: usize,
+    /// The item type every rucksack of the group carries
+    pub badge: Result<char, BadgeError>,
+}
+
+/// Why the badge of a group could not be found
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BadgeError {
+    /// No item type is carried by every rucksack of the group
+    NoCommonItem,
+    /// The passed item types are all carried by every rucksack of the group
+    SeveralCommonItems(Vec<char>),
+    /// The last group has fewer rucksacks than every other group
+    Incomplete,
+}
+
+impl fmt::Display for BadgeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BadgeError::NoCommonItem => write!(f, "no item common to every rucksack"),
+            BadgeError::SeveralCommonItems(items) => write!(
+                f,
+                "several items common to every rucksack: {}",
+                items.iter().collect::<String>()
+            ),
+            BadgeError::Incomplete => write!(f, "fewer rucksacks than a full group"),
+        }
+    }
+}
+
+/// The badge of every group of the passed size, with the rucksacks grouped in the order
+/// they are listed
+fn get_badges(rucksacks: &[Rucksack], group_size: usize) -> Vec<Result<char, BadgeError>> {
+    rucksacks
+        .chunks(group_size.max(1))
+        .map(|group| {
+            let common = group
+                .iter()
+                .fold(ALL_ITEMS, |common, rucksack| common & rucksack.items());
+            match get_items(common)[..] {
+                _ if group.len() < group_size => Err(BadgeError::Incomplete),
+                [] => Err(BadgeError::NoCommonItem),
+                [badge] => Ok(badge),
+                ref items => Err(BadgeError::SeveralCommonItems(items.to_vec())),
+            }
+        })
+        .collect()
+}
+
+/// Parses the passed input and finds the badge of every group of the passed size, along
+/// with the lines of the input the group's rucksacks are on
+pub fn find_badges(data: &str, group_size: usize) -> Result<Vec<Group>, ParseError> {
+    let normalized = NormalizedInput::new(data, Day3::BLANK_LINES);
+    let rucksacks = Day3::parse(&normalized.text).map_err(|error| normalized.locate(error))?;
+    let group_size = group_size.max(1);
+
+    Ok(get_badges(&rucksacks, group_size)
+        .into_iter()
+        .enumerate()
+        .map(|(i, badge)| {
+            let first_index = i * group_size;
+            let last_index = (first_index + group_size).min(rucksacks.len()) - 1;
+            // every normalized line comes from a line of the input
+            let line_number = |index| normalized.line_number(index).unwrap_or(index + 1);
+
+            Group {
+                first_line: line_number(first_index),
+                last_line: line_number(last_index),
+                badge,
+            }
+        })
+        .collect())
+}
+
+/// Renders the badge of every group, or why it has none
+pub fn format_badges(groups: &[Group]) -> String {
+    let mut badges = String::new();
+    for group in groups {
+        let lines = format!("{}-{}", group.first_line, group.last_line);
+        badges.push_str(&match &group.badge {
+            Ok(badge) => format!("Lines {:<11} badge {}\n", lines, badge),
+            Err(error) => format!("Lines {:<11} error: {}\n", lines, error),
+        });
+    }
+    badges.pop();
+    badges
+}
+
+fn get_mask(items: &str) -> u64 {
+    items
+        .chars()
+        .fold(0, |mask, c| mask | 1 << (get_priority(c) - 1))
+}
+
+/// The byte index of the first character of the passed items that is not from a to z or
+/// A to Z, along with the character
+fn find_invalid_item(items: &str) -> Option<(usize, char)> {
+    items.char_indices().find(|(_, c)| !c.is_ascii_alphabetic())
+}
+
+/// Every item type in the passed mask, from the lowest priority to the highest
+fn get_items(mut mask: u64) -> Vec<char> {
+    let mut items = vec![];
+    while mask != 0 {
+        items.push(get_item(mask.trailing_zeros() + 1));
+        mask &= mask - 1;
+    }
+    items
+}
+
+fn get_item(priority: u32) -> char {
+    match priority <= 26 {
+        true => (priority + 96) as u8 as char,
+        false => (priority + 38) as u8 as char,
+    }
+}
+
+fn get_priority(c: char) -> u32 {
+    match c.is_lowercase() {
+        true => c as u32 - 96,
+        false => c as u32 - 38,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse_input, Answer, Solver};
+
+    use crate::{
+        find_badges, format_badges, get_items, get_mask, get_priority, BadgeError, Day3, Group,
+        Rucksack, GROUP_SIZE,
+    };
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day3>(EXAMPLE).unwrap();
+        assert_eq!(Day3::part_one(&input), Answer::from(157));
+        assert_eq!(Day3::part_two(&input), Answer::from(70));
+    }
+
+    #[test]
+    fn test_masks() {
+        assert_eq!(get_mask("aaZ"), 1 | 1 << 51);
+        assert_eq!(
+            get_items(get_mask("pLPvts"))
+                .into_iter()
+                .map(get_priority)
+                .sum::<u32>(),
+            16 + 38 + 42 + 22 + 20 + 19
+        );
+
+        let rucksack = Rucksack::new("abcBad");
+        assert_eq!(rucksack.shared_items(), get_mask("a"));
+        assert_eq!(rucksack.items(), get_mask("abcdB"));
+        assert_eq!(Rucksack::new("abcd").shared_items(), 0);
+    }
+
+    #[test]
+    fn test_find_badges() {
+        let groups = find_badges(EXAMPLE, GROUP_SIZE).unwrap();
+        assert_eq!(
+            groups
+                .iter()
+                .map(|group| group.badge.clone())
+                .collect::<Vec<_>>(),
+            [Ok('r'), Ok('Z')]
+        );
+        assert_eq!(
+            format_badges(&groups),
+            "Lines 1-3         badge r\nLines 4-6         badge Z"
+        );
+
+        let groups = find_badges(EXAMPLE, 2).unwrap();
+        assert_eq!(
+            groups[0],
+            Group {
+                first_line: 1,
+                last_line: 2,
+                badge: Err(BadgeError::SeveralCommonItems(vec![
+                    'f', 'r', 's', 'F', 'M'
+                ]))
+            }
+        );
+        let groups = find_badges(EXAMPLE, 4).unwrap();
+        assert_eq!((groups[1].first_line, groups[1].last_line), (5, 6));
+        assert_eq!(groups[1].badge, Err(BadgeError::Incomplete));
+
+        // blank lines are skipped without shifting the lines of later rucksacks
+        let data = "ab\n\ncd\nab\n\nab\nab\nab";
+        let groups = find_badges(data, GROUP_SIZE).unwrap();
+        assert_eq!(
+            groups[0],
+            Group {
+                first_line: 1,
+                last_line: 4,
+                badge: Err(BadgeError::NoCommonItem)
+            }
+        );
+        assert_eq!((groups[1].first_line, groups[1].last_line), (6, 8));
+        assert_eq!(find_badges("ab\n\nabc", GROUP_SIZE).unwrap_err().line, 3);
+
+        let rucksacks = parse_input::<Day3>(data).unwrap();
+        assert_eq!(Day3::part_two(&rucksacks), Answer::Unsolved);
+        assert_eq!(get_items(get_mask("Za")), ['a', 'Z']);
+    }
+
+    #[test]
+    fn test_parse() {
+        let error = parse_input::<Day3>("abcb\nabc").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = parse_input::<Day3>("ab c").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 3));
+
+        let rucksacks = parse_input::<Day3>("abcb\nabab").unwrap();
+        assert_eq!(Day3::part_one(&rucksacks), Answer::Unsolved);
+    }
+}
@@ -1,90 +1,30 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
+use aoc_common::{read_input_or_exit, solve};
+use day3::{
+    find_badges, format_badges, format_diagnoses, validate_rucksacks, Day3, FILE_NAME, GROUP_SIZE,
 };
+use std::env;
 
-const FILE_NAME: &str = "data1.txt";
-const PART_TWO_SACK_COUNT: usize = 2;
+/// Also lists the badge of every group, optionally followed by the number of rucksacks
+/// in a group
+const BADGES_FLAG: &str = "--badges";
+/// Also lists every malformed rucksack along with the item moves that would fix it
+const VALIDATE_FLAG: &str = "--validate";
 
 fn main() {
-    let mut data = fs::read_to_string(FILE_NAME).expect("Something went wrong reading the file");
-    data.pop();
+    let data = read_input_or_exit(FILE_NAME);
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    part_one(&data);
-    part_two(&data);
-}
-
-fn part_one(data: &str) {
-    let mut total = 0;
-
-    data.lines().for_each(|line| {
-        let chars = line.chars().collect::<Vec<_>>();
-        let (first_items, second_items) = chars.split_at(line.len() / 2);
-
-        let first_items: HashSet<&char> = HashSet::from_iter(first_items.iter());
-        let second_items: HashSet<&char> = HashSet::from_iter(second_items.iter());
-
-        let intersect = first_items.intersection(&second_items);
-        let duplicate_char = **intersect.collect::<Vec<_>>()[0];
-
-        total += get_priority(duplicate_char);
-    });
-
-    println!("Part one: {}", total);
-}
-
-fn part_two(data: &str) {
-    let mut total = 0;
-    let mut first_sack: HashSet<char> = HashSet::new();
-    let mut second_sack: HashSet<char> = HashSet::new();
-    let mut third_sack: HashSet<char> = HashSet::new();
-    let mut char_counts: HashMap<char, usize> = HashMap::new();
-
-    data.lines().for_each(|line| {
-        let chars = line.chars().collect::<Vec<_>>();
-        if first_sack.is_empty() {
-            first_sack = HashSet::from_iter(chars.into_iter());
-        } else if second_sack.is_empty() {
-            second_sack = HashSet::from_iter(chars.into_iter());
-        } else if third_sack.is_empty() {
-            third_sack = HashSet::from_iter(chars.into_iter());
-
-            for c in &first_sack {
-                count_chars(*c, &mut char_counts)
-            }
-            for c in &second_sack {
-                count_chars(*c, &mut char_counts)
-            }
-            for c in &third_sack {
-                count_chars(*c, &mut char_counts)
-            }
-
-            for (c, count) in &char_counts {
-                if count == &PART_TWO_SACK_COUNT {
-                    total += get_priority(*c);
-                }
-            }
-
-            first_sack.clear();
-            second_sack.clear();
-            third_sack.clear();
-            char_counts.clear();
+    if args.iter().any(|arg| arg == VALIDATE_FLAG) {
+        println!("{}", format_diagnoses(&validate_rucksacks(&data)));
+    }
+    if let Some(i) = args.iter().position(|arg| arg == BADGES_FLAG) {
+        let group_size = match args.get(i + 1).map(|arg| arg.parse()) {
+            Some(Ok(group_size)) if group_size > 0 => group_size,
+            _ => GROUP_SIZE,
+        };
+        if let Ok(groups) = find_badges(&data, group_size) {
+            println!("{}", format_badges(&groups));
         }
-    });
-
-    println!("Part two: {}", total);
-}
-
-fn count_chars(c: char, char_counts: &mut HashMap<char, usize>) {
-    match char_counts.get(&c) {
-        Some(count) => char_counts.insert(c, count + 1),
-        None => char_counts.insert(c, 1),
-    };
-}
-
-fn get_priority(c: char) -> u32 {
-    match c.is_lowercase() {
-        true => c as u32 - 96,
-        false => c as u32 - 38,
     }
+    solve::<Day3>(FILE_NAME, &data);
 }
@@ -0,0 +1,313 @@
+//! Finds the rucksacks that do not hold exactly one item type in both compartments and
+//! suggests the fewest item moves between compartments that would fix them.
+
+use std::fmt;
+
+use crate::{find_invalid_item, get_item, get_priority};
+
+/// Number of item types, one for every priority
+const ITEM_TYPES: usize = 52;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Compartment {
+    First,
+    Second,
+}
+
+impl Compartment {
+    fn other(self) -> Compartment {
+        match self {
+            Compartment::First => Compartment::Second,
+            Compartment::Second => Compartment::First,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Compartment::First => "first",
+            Compartment::Second => "second",
+        }
+    }
+}
+
+/// Moves items of one type to the other compartment
+#[derive(Debug, Eq, PartialEq)]
+pub struct Move {
+    pub item: char,
+    pub count: usize,
+    pub from: Compartment,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} from the {} compartment to the {}",
+            self.count,
+            self.item,
+            self.from.name(),
+            self.from.other().name()
+        )
+    }
+}
+
+/// What is wrong with a rucksack
+#[derive(Debug, Eq, PartialEq)]
+pub enum Problem {
+    /// An item at the passed column, starting from 1, that is not from a to z or A to Z
+    InvalidItem {
+        column: usize,
+        item: char,
+    },
+    /// The passed odd number of items, which cannot be split into two compartments
+    OddLength(usize),
+    NoSharedItem,
+    /// The passed item types are all in both compartments
+    SeveralSharedItems(Vec<char>),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::InvalidItem { column, item } => write!(
+                f,
+                "item `{}` at column {} is not from a to z or A to Z",
+                item, column
+            ),
+            Problem::OddLength(len) => write!(
+                f,
+                "{} items cannot be split into two equal compartments",
+                len
+            ),
+            Problem::NoSharedItem => write!(f, "no item is in both compartments"),
+            Problem::SeveralSharedItems(items) => write!(
+                f,
+                "several items are in both compartments: {}",
+                items.iter().collect::<String>()
+            ),
+        }
+    }
+}
+
+/// A malformed rucksack along with how to fix it
+#[derive(Debug, Eq, PartialEq)]
+pub struct Diagnosis {
+    /// Line of the rucksack in the input, starting from 1
+    pub line: usize,
+    pub problem: Problem,
+    /// The fewest moves that leave exactly one item type in both compartments, if moving
+    /// items can fix the rucksack
+    pub moves: Option<Vec<Move>>,
+}
+
+/// Checks the rucksack on every line of the passed input, which does not need to be
+/// parsable, and diagnoses every malformed rucksack. Only the first problem of every
+/// rucksack is reported. Moved items are not moved back, so the compartments may end up
+/// holding different numbers of items.
+pub fn validate_rucksacks(data: &str) -> Vec<Diagnosis> {
+    data.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty())
+        .filter_map(|(line_number, line)| {
+            let (problem, moves) = diagnose(line)?;
+            Some(Diagnosis {
+                line: line_number,
+                problem,
+                moves,
+            })
+        })
+        .collect()
+}
+
+/// The problem of the passed rucksack and the moves that fix it, if it has a problem
+fn diagnose(items: &str) -> Option<(Problem, Option<Vec<Move>>)> {
+    if let Some((j, item)) = find_invalid_item(items) {
+        let column = items[..j].chars().count() + 1;
+        return Some((Problem::InvalidItem { column, item }, None));
+    }
+    if !items.len().is_multiple_of(2) {
+        return Some((Problem::OddLength(items.len()), None));
+    }
+
+    let (first, second) = items.split_at(items.len() / 2);
+    let counts = [count_items(first), count_items(second)];
+    let shared: Vec<usize> = (0..ITEM_TYPES)
+        .filter(|i| counts[0][*i] > 0 && counts[1][*i] > 0)
+        .collect();
+
+    match shared.len() {
+        0 => Some((Problem::NoSharedItem, share_item(&counts))),
+        1 => None,
+        _ => Some((
+            Problem::SeveralSharedItems(shared.iter().map(|i| to_item(*i)).collect()),
+            Some(unshare_items(&counts, &shared)),
+        )),
+    }
+}
+
+/// Number of items of every type, indexed by priority minus one
+fn count_items(items: &str) -> [usize; ITEM_TYPES] {
+    let mut counts = [0; ITEM_TYPES];
+    for c in items.chars() {
+        counts[get_priority(c) as usize - 1] += 1;
+    }
+    counts
+}
+
+fn to_item(i: usize) -> char {
+    get_item(i as u32 + 1)
+}
+
+/// A single item moved from a compartment holding several of its type, so that the
+/// type ends up in both compartments, if any compartment holds several items of a type
+fn share_item(counts: &[[usize; ITEM_TYPES]; 2]) -> Option<Vec<Move>> {
+    [Compartment::First, Compartment::Second]
+        .into_iter()
+        .zip(counts)
+        .find_map(|(from, counts)| {
+            let i = counts.iter().position(|count| *count > 1)?;
+            Some(vec![Move {
+                item: to_item(i),
+                count: 1,
+                from,
+            }])
+        })
+}
+
+/// Every shared type but one has all its items gathered into the compartment already
+/// holding the most of them. The type kept shared is the one that would take the most
+/// moves to gather.
+fn unshare_items(counts: &[[usize; ITEM_TYPES]; 2], shared: &[usize]) -> Vec<Move> {
+    let gather = |i: usize| match counts[0][i] <= counts[1][i] {
+        true => Move {
+            item: to_item(i),
+            count: counts[0][i],
+            from: Compartment::First,
+        },
+        false => Move {
+            item: to_item(i),
+            count: counts[1][i],
+            from: Compartment::Second,
+        },
+    };
+
+    let mut moves: Vec<Move> = shared.iter().map(|i| gather(*i)).collect();
+    // the first of the types taking the most moves is kept
+    let kept = moves
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, mv)| mv.count)
+        .map(|(i, _)| i);
+    if let Some(kept) = kept {
+        moves.remove(kept);
+    }
+    moves
+}
+
+/// Renders every diagnosis on its own line along with the moves that fix it
+pub fn format_diagnoses(diagnoses: &[Diagnosis]) -> String {
+    if diagnoses.is_empty() {
+        return "Every rucksack holds exactly one item type in both compartments".to_string();
+    }
+
+    let mut report = String::new();
+    for diagnosis in diagnoses {
+        let fix = match &diagnosis.moves {
+            Some(moves) => format!(
+                "move {}",
+                moves
+                    .iter()
+                    .map(|mv| mv.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None => "moving items cannot fix it".to_string(),
+        };
+        report.push_str(&format!(
+            "Line {}: {}; {}\n",
+            diagnosis.line, diagnosis.problem, fix
+        ));
+    }
+    report.pop();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::{
+        diagnose, format_diagnoses, validate_rucksacks, Compartment, Move, Problem,
+    };
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_diagnose() {
+        assert_eq!(diagnose("abcb"), None);
+        assert_eq!(
+            diagnose("ab1cA"),
+            Some((
+                Problem::InvalidItem {
+                    column: 3,
+                    item: '1'
+                },
+                None
+            ))
+        );
+        assert_eq!(diagnose("abcAa"), Some((Problem::OddLength(5), None)));
+        assert_eq!(
+            diagnose("aabc"),
+            Some((
+                Problem::NoSharedItem,
+                Some(vec![Move {
+                    item: 'a',
+                    count: 1,
+                    from: Compartment::First
+                }])
+            ))
+        );
+        assert_eq!(diagnose("abcd"), Some((Problem::NoSharedItem, None)));
+
+        // b takes the most moves to gather so it stays shared
+        assert_eq!(
+            diagnose("abbcCaabbc"),
+            Some((
+                Problem::SeveralSharedItems(vec!['a', 'b', 'c']),
+                Some(vec![
+                    Move {
+                        item: 'a',
+                        count: 1,
+                        from: Compartment::First
+                    },
+                    Move {
+                        item: 'c',
+                        count: 1,
+                        from: Compartment::First
+                    }
+                ])
+            ))
+        );
+    }
+
+    #[test]
+    fn test_validate_rucksacks() {
+        assert!(validate_rucksacks(EXAMPLE).is_empty());
+
+        let data = "abcb\r\n\r\nabcd\nab-A\nabab";
+        let diagnoses = validate_rucksacks(data);
+        assert_eq!(
+            diagnoses
+                .iter()
+                .map(|diagnosis| diagnosis.line)
+                .collect::<Vec<_>>(),
+            [3, 4, 5]
+        );
+        assert_eq!(
+            format_diagnoses(&diagnoses),
+            "Line 3: no item is in both compartments; moving items cannot fix it\n\
+             Line 4: item `-` at column 3 is not from a to z or A to Z; moving items cannot fix it\n\
+             Line 5: several items are in both compartments: ab; move 1 b from the first compartment to the second"
+        );
+    }
+}
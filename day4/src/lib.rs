@@ -0,0 +1,249 @@
+use aoc_common::{
+    input_file,
+    interval::{Interval, IntervalSet},
+    parse_token, Answer, ParseError, Solver,
+};
+use std::fmt;
+
+pub const FILE_NAME: &str = input_file!();
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    /// The section assignments of every elf pair
+    type Input = Vec<(Interval<i64>, Interval<i64>)>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines()
+            .enumerate()
+            .map(|(i, line)| match line.split_once(',') {
+                Some((first_range, second_range)) => Ok((
+                    parse_range(i, line, first_range)?,
+                    parse_range(i, line, second_range)?,
+                )),
+                None => Err(ParseError::new(
+                    i,
+                    line,
+                    line,
+                    "`<start>-<end>,<start>-<end>`",
+                )),
+            })
+            .collect()
+    }
+
+    fn part_one(pairs: &Self::Input) -> Answer {
+        let mut total = 0;
+
+        pairs.iter().for_each(|(first_range, second_range)| {
+            if first_range.is_subset(*second_range) || second_range.is_subset(*first_range) {
+                total += 1;
+            }
+        });
+
+        total.into()
+    }
+
+    fn part_two(pairs: &Self::Input) -> Answer {
+        let mut total = 0;
+
+        pairs.iter().for_each(|(first_range, second_range)| {
+            if first_range.overlaps(*second_range) {
+                total += 1;
+            }
+        });
+
+        total.into()
+    }
+}
+
+fn parse_range(line_index: usize, line: &str, range: &str) -> Result<Interval<i64>, ParseError> {
+    let (start, end) = match range.split_once('-') {
+        Some(bounds) => bounds,
+        None => return Err(ParseError::new(line_index, line, range, "`<start>-<end>`")),
+    };
+    let start = parse_token(line_index, line, start, "a section number")?;
+    let end = parse_token(line_index, line, end, "a section number")?;
+
+    match Interval::new(start, end) {
+        Some(interval) => Ok(interval),
+        None => Err(ParseError::new(
+            line_index,
+            line,
+            range,
+            "a range that does not start after it ends",
+        )),
+    }
+}
+
+/// An analysis of the section assignments of every elf pair
+#[derive(Debug, Eq, PartialEq)]
+pub struct CoverageReport {
+    /// Number of sections assigned to at least one elf
+    pub covered: i64,
+    /// The sections between the lowest and the highest assigned section that no elf is
+    /// assigned
+    pub gaps: Vec<Interval<i64>>,
+    /// The lowest section assigned to the most elves, along with the number of elves
+    pub most_overlapped: Option<(i64, usize)>,
+    /// Number, starting from 1, of every pair whose sections are all also assigned to
+    /// elves of other pairs
+    pub redundant_pairs: Vec<usize>,
+}
+
+pub fn get_coverage_report(pairs: &[(Interval<i64>, Interval<i64>)]) -> CoverageReport {
+    let covered: IntervalSet<i64> = pairs
+        .iter()
+        .flat_map(|(first_range, second_range)| [*first_range, *second_range])
+        .collect();
+    let overlaps = get_overlaps(pairs);
+
+    let mut most_overlapped: Option<(i64, usize)> = None;
+    for (sections, elves) in &overlaps {
+        if most_overlapped.is_none_or(|(_, most_elves)| *elves > most_elves) {
+            most_overlapped = Some((sections.start(), *elves));
+        }
+    }
+
+    let redundant_pairs = pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (first_range, second_range))| {
+            overlaps
+                .iter()
+                .filter(|(sections, _)| {
+                    sections.overlaps(*first_range) || sections.overlaps(*second_range)
+                })
+                .all(|(sections, elves)| {
+                    let pair_elves = [first_range, second_range]
+                        .iter()
+                        .filter(|range| range.contains(sections.start()))
+                        .count();
+                    *elves > pair_elves
+                })
+        })
+        .map(|(i, _)| i + 1)
+        .collect();
+
+    CoverageReport {
+        covered: covered.len(),
+        gaps: covered.gaps().intervals().to_vec(),
+        most_overlapped,
+        redundant_pairs,
+    }
+}
+
+/// Number of elves assigned to every section, as sorted runs of sections assigned to the
+/// same number of elves. Sections assigned to no elf are left out.
+fn get_overlaps(pairs: &[(Interval<i64>, Interval<i64>)]) -> Vec<(Interval<i64>, usize)> {
+    // the change in the number of assigned elves at the start and after the end of
+    // every range
+    let mut changes: Vec<(i64, isize)> = pairs
+        .iter()
+        .flat_map(|(first_range, second_range)| [first_range, second_range])
+        .flat_map(|range| [(range.start(), 1), (range.end() + 1, -1)])
+        .collect();
+    changes.sort();
+
+    let mut overlaps = vec![];
+    let mut elves = 0;
+    for change in changes.windows(2) {
+        elves += change[0].1;
+        if let Some(sections) = Interval::new(change[0].0, change[1].0 - 1) {
+            if elves > 0 {
+                overlaps.push((sections, elves as usize));
+            }
+        }
+    }
+
+    overlaps
+}
+
+/// Renders the report over a line for every figure, with sections and pairs as ranges
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Sections covered: {}", self.covered)?;
+        writeln!(f, "Uncovered gaps: {}", join_or_none(&self.gaps))?;
+        match self.most_overlapped {
+            Some((section, elves)) => writeln!(
+                f,
+                "Most overlapped section: {} assigned to {} elves",
+                section, elves
+            )?,
+            None => writeln!(f, "Most overlapped section: none")?,
+        }
+        // runs of consecutive pairs are shown as a range to keep dense inputs readable
+        let redundant_pairs: IntervalSet<i64> = self
+            .redundant_pairs
+            .iter()
+            .filter_map(|pair| Interval::new(*pair as i64, *pair as i64))
+            .collect();
+        write!(
+            f,
+            "Redundant pairs: {}",
+            join_or_none(redundant_pairs.intervals())
+        )
+    }
+}
+
+fn join_or_none<T: fmt::Display>(items: &[T]) -> String {
+    match items.is_empty() {
+        true => "none".to_string(),
+        false => items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse_input, Answer, Solver};
+
+    use crate::{get_coverage_report, Day4};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day4>(EXAMPLE).unwrap();
+        assert_eq!(Day4::part_one(&input), Answer::from(2));
+        assert_eq!(Day4::part_two(&input), Answer::from(4));
+    }
+
+    #[test]
+    fn test_wide_ranges() {
+        let input = parse_input::<Day4>("1-4000000000,2-3999999999\n5-6,6-4000000000").unwrap();
+        assert_eq!(Day4::part_one(&input), Answer::from(1));
+        assert_eq!(Day4::part_two(&input), Answer::from(2));
+        assert!(parse_input::<Day4>("4-3,1-2").is_err());
+    }
+
+    #[test]
+    fn test_get_coverage_report() {
+        let report = get_coverage_report(&parse_input::<Day4>(EXAMPLE).unwrap());
+        assert_eq!(report.covered, 8);
+        assert!(report.gaps.is_empty());
+        assert_eq!(report.most_overlapped, Some((6, 8)));
+        assert_eq!(report.redundant_pairs, [1, 2, 4, 5, 6]);
+
+        let pairs = parse_input::<Day4>("1-3,2-2\n2-2,9-9\n6-7,1-4000000000").unwrap();
+        let report = get_coverage_report(&pairs);
+        assert_eq!(report.covered, 4000000000);
+        assert_eq!(report.most_overlapped, Some((2, 4)));
+        assert_eq!(report.redundant_pairs, [1, 2]);
+        assert_eq!(
+            report.to_string(),
+            "Sections covered: 4000000000\n\
+             Uncovered gaps: none\n\
+             Most overlapped section: 2 assigned to 4 elves\n\
+             Redundant pairs: 1-2"
+        );
+
+        let pairs = parse_input::<Day4>("1-2,4-4\n7-9,4-4").unwrap();
+        let report = get_coverage_report(&pairs);
+        assert_eq!(report.gaps.len(), 2);
+        assert_eq!(report.redundant_pairs, []);
+        assert!(report.to_string().contains("Uncovered gaps: 3-3, 5-6\n"));
+    }
+}
@@ -1,50 +1,18 @@
-use std::{collections::HashSet, fs};
+use aoc_common::{parse_input, read_input_or_exit, solve};
+use day4::{get_coverage_report, Day4, FILE_NAME};
+use std::env;
 
-const FILE_NAME: &str = "data1.txt";
+/// Also reports the sections covered, the gaps between them, the most overlapped section
+/// and the redundant pairs
+const REPORT_FLAG: &str = "--report";
 
 fn main() {
-    let mut data = fs::read_to_string(FILE_NAME).expect("Something went wrong reading the file");
-    data.pop();
+    let data = read_input_or_exit(FILE_NAME);
 
-    part_one(&data);
-    part_two(&data);
-}
-
-fn part_one(data: &str) {
-    let mut total = 0;
-
-    data.lines().for_each(|line| {
-        let ranges = line.split(',').collect::<Vec<&str>>();
-        let first_range = parse_range(ranges[0]);
-        let second_range = parse_range(ranges[1]);
-
-        if first_range.is_subset(&second_range) || second_range.is_subset(&first_range) {
-            total += 1;
+    if env::args().skip(1).any(|arg| arg == REPORT_FLAG) {
+        if let Ok(pairs) = parse_input::<Day4>(&data) {
+            println!("{}", get_coverage_report(&pairs));
         }
-    });
-
-    println!("Part one: {}", total);
-}
-
-fn part_two(data: &str) {
-    let mut total = 0;
-
-    data.lines().for_each(|line| {
-        let ranges = line.split(',').collect::<Vec<&str>>();
-        let first_range = parse_range(ranges[0]);
-        let second_range = parse_range(ranges[1]);
-
-        if !first_range.is_disjoint(&second_range) {
-            total += 1;
-        }
-    });
-
-    println!("Part two: {}", total);
-}
-
-fn parse_range(range: &str) -> HashSet<i32> {
-    let bounds = range.split('-').collect::<Vec<&str>>();
-    let start: i32 = bounds[0].parse().unwrap();
-    let end: i32 = bounds[1].parse().unwrap();
-    HashSet::from_iter(start..=end)
+    }
+    solve::<Day4>(FILE_NAME, &data);
 }
@@ -0,0 +1,244 @@
+use aoc_common::{input_file, parse_token, Answer, BlankLines, ParseError, Solver};
+
+pub const FILE_NAME: &str = input_file!();
+
+const MOVE_FORM: &str = "`move <quantity> from <stack> to <stack>`";
+const MOVE_TOKEN_COUNT: usize = 6;
+const MOVE_TOKEN: &str = "move";
+const FROM_TOKEN: &str = "from";
+const TO_TOKEN: &str = "to";
+const MOVE_TOKEN_INDEX: usize = 0;
+const FROM_TOKEN_INDEX: usize = 2;
+const TO_TOKEN_INDEX: usize = 4;
+const MOVE_QUANTITY_INDEX: usize = 1;
+const MOVE_START_STACK_INDEX: usize = 3;
+const MOVE_END_STACK_INDEX: usize = 5;
+
+const CHARS_BETWEEN_CRATES: usize = 4;
+const FIRST_CRATE_CHAR_INDEX: usize = 1;
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input = (Vec<Stack>, Vec<Move>);
+
+    const BLANK_LINES: BlankLines = BlankLines::Separator;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = data.lines().collect();
+
+        // the stacks and moves are separated by a blank line
+        let separator_index = match lines.iter().position(|line| line.is_empty()) {
+            Some(index) => index,
+            None => {
+                return Err(ParseError::end_of_line(
+                    lines.len(),
+                    "",
+                    "a blank line between the stacks and the moves",
+                ))
+            }
+        };
+
+        let stacks = parse_stacks(&lines[..separator_index])?;
+        let moves = parse_moves(&lines, separator_index + 1, &stacks)?;
+        Ok((stacks, moves))
+    }
+
+    fn part_one((stacks, moves): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+        process_moves(moves, &mut stacks, CrateMover::First);
+        get_message(&stacks).into()
+    }
+
+    fn part_two((stacks, moves): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+        process_moves(moves, &mut stacks, CrateMover::Second);
+        get_message(&stacks).into()
+    }
+}
+
+enum CrateMover {
+    First,
+    Second,
+}
+
+/// Processes the passed moves by moving crates between the passed stacks.
+/// The method to move crates depends on CrateMover. CrateMover::First picks
+/// up a single crate at a time which reverses the crate order on during moves
+/// where as CrateMover::Second picks up all crates at once which retains the
+/// crate order during moves.
+fn process_moves(moves: &[Move], stacks: &mut [Stack], crate_mover: CrateMover) {
+    moves.iter().for_each(|m| {
+        let start_stack = &mut stacks[m.start_stack - 1].crates;
+        let crates_to_move = (start_stack.len() - m.quantity)..=(start_stack.len() - 1);
+
+        let moved_crates = match crate_mover {
+            // TODO is initializing another String wasteful?
+            CrateMover::First => start_stack.drain(crates_to_move).rev().collect::<String>(),
+            CrateMover::Second => start_stack.drain(crates_to_move).collect::<String>(),
+        };
+
+        let end_stack = &mut stacks[m.end_stack - 1].crates;
+        end_stack.push_str(moved_crates.as_str());
+    });
+}
+
+#[derive(Clone, Default)] // TODO is this clone removeable?
+pub struct Stack {
+    crates: String,
+}
+
+pub struct Move {
+    quantity: usize,
+    start_stack: usize,
+    end_stack: usize,
+}
+
+/// Parses every move from the line at the passed index to the last line. Every crate
+/// mover moves the same number of crates, so a move taking more crates than its start
+/// stack holds is rejected here.
+fn parse_moves(
+    lines: &[&str],
+    first_line_index: usize,
+    stacks: &[Stack],
+) -> Result<Vec<Move>, ParseError> {
+    let mut moves = vec![];
+    let mut heights: Vec<usize> = stacks.iter().map(|stack| stack.crates.len()).collect();
+
+    for (line_index, line) in lines.iter().enumerate().skip(first_line_index) {
+        let m = parse_move(line_index, line, stacks.len())?;
+
+        let height = heights[m.start_stack - 1];
+        if m.quantity > height {
+            return Err(ParseError::new(
+                line_index,
+                line,
+                line.split(' ').nth(MOVE_QUANTITY_INDEX).unwrap_or(line),
+                &format!("a crate quantity of at most {}", height),
+            ));
+        }
+        heights[m.start_stack - 1] -= m.quantity;
+        heights[m.end_stack - 1] += m.quantity;
+
+        moves.push(m);
+    }
+
+    Ok(moves)
+}
+
+fn parse_move(line_index: usize, input: &str, stack_count: usize) -> Result<Move, ParseError> {
+    let tokens: Vec<&str> = input.split(' ').collect();
+
+    let is_move = tokens.len() == MOVE_TOKEN_COUNT
+        && tokens[MOVE_TOKEN_INDEX] == MOVE_TOKEN
+        && tokens[FROM_TOKEN_INDEX] == FROM_TOKEN
+        && tokens[TO_TOKEN_INDEX] == TO_TOKEN;
+    if !is_move {
+        return Err(ParseError::new(line_index, input, input, MOVE_FORM));
+    }
+
+    let expected_stack = format!("a stack number from 1 to {}", stack_count);
+    let parse_stack_number = |token: &str| -> Result<usize, ParseError> {
+        match parse_token(line_index, input, token, &expected_stack)? {
+            stack if (1..=stack_count).contains(&stack) => Ok(stack),
+            _ => Err(ParseError::new(line_index, input, token, &expected_stack)),
+        }
+    };
+
+    Ok(Move {
+        quantity: parse_token(
+            line_index,
+            input,
+            tokens[MOVE_QUANTITY_INDEX],
+            "a crate quantity",
+        )?,
+        start_stack: parse_stack_number(tokens[MOVE_START_STACK_INDEX])?,
+        end_stack: parse_stack_number(tokens[MOVE_END_STACK_INDEX])?,
+    })
+}
+
+/// Parses the stacks drawing. The last line numbers the stacks and every line
+/// above it holds a layer of crates.
+fn parse_stacks(lines: &[&str]) -> Result<Vec<Stack>, ParseError> {
+    let (stack_line, crate_lines) = match lines.split_last() {
+        Some(lines) => lines,
+        None => return Err(ParseError::end_of_line(0, "", "a line of stack numbers")),
+    };
+
+    let mut stacks = parse_stack(crate_lines.len(), stack_line)?;
+    for (line_index, line) in crate_lines.iter().enumerate().rev() {
+        parse_crate(line_index, line, &mut stacks)?;
+    }
+
+    Ok(stacks)
+}
+
+fn parse_stack(line_index: usize, input: &str) -> Result<Vec<Stack>, ParseError> {
+    let mut tokens_count = 0;
+    for token in input.split(' ').filter(|&t| !t.is_empty()) {
+        parse_token::<usize>(line_index, input, token, "a stack number")?;
+        tokens_count += 1;
+    }
+
+    Ok(vec![Stack::default(); tokens_count])
+}
+
+fn parse_crate(line_index: usize, input: &str, stacks: &mut [Stack]) -> Result<(), ParseError> {
+    for (i, char) in input.char_indices() {
+        if i % CHARS_BETWEEN_CRATES == FIRST_CRATE_CHAR_INDEX && !char.eq(&' ') {
+            match stacks.get_mut((i - 1) / CHARS_BETWEEN_CRATES) {
+                Some(stack) => stack.crates.push(char),
+                None => {
+                    return Err(ParseError::new(
+                        line_index,
+                        input,
+                        &input[i..i + char.len_utf8()],
+                        &format!("a crate above one of the {} stacks", stacks.len()),
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The message is the crate character of the crate on top of every stack
+/// from left to right. For example, a left stack with a top crate A, middle stack
+/// with top crate B and right stack with top crate C, would have message "ABC".
+/// Empty stacks have no crate on top so they are left out of the message.
+fn get_message(stacks: &[Stack]) -> String {
+    stacks
+        .iter()
+        .filter_map(|stack| stack.crates.chars().last())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse_input, Answer, Solver};
+
+    use crate::Day5;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day5>(EXAMPLE).unwrap();
+        assert_eq!(Day5::part_one(&input), Answer::from("CMZ"));
+        assert_eq!(Day5::part_two(&input), Answer::from("MCD"));
+    }
+
+    #[test]
+    fn test_crate_quantities() {
+        let input = parse_input::<Day5>("[A]    \n 1   2 \n\nmove 1 from 1 to 2").unwrap();
+        assert_eq!(Day5::part_one(&input), Answer::from("A"));
+
+        let error =
+            parse_input::<Day5>("[A]    \n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1")
+                .err()
+                .unwrap();
+        assert_eq!((error.line, error.column), (5, 6));
+        assert_eq!(error.expected, "a crate quantity of at most 1");
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_common::{input_file, Answer, ParseError, Solver};
+use std::collections::HashMap;
+
+pub const FILE_NAME: &str = input_file!();
+const FIRST_WINDOW_SIZE: usize = 4;
+const SECOND_WINDOW_SIZE: usize = 14;
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    /// The datastream buffer
+    type Input = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        // the datastream is a single line
+        match data.lines().enumerate().nth(1) {
+            Some((i, line)) => Err(ParseError::new(i, line, line, "end of input")),
+            None => Ok(data.to_string()),
+        }
+    }
+
+    fn part_one(datastream: &Self::Input) -> Answer {
+        match find_marker(datastream, FIRST_WINDOW_SIZE) {
+            Ok(index) => index.into(),
+            Err(_) => Answer::Unsolved,
+        }
+    }
+
+    fn part_two(datastream: &Self::Input) -> Answer {
+        match find_marker(datastream, SECOND_WINDOW_SIZE) {
+            Ok(index) => index.into(),
+            Err(_) => Answer::Unsolved,
+        }
+    }
+}
+
+/// The number of characters read once the last `window_size` characters are all different
+fn find_marker(data: &str, window_size: usize) -> Result<usize, &'static str> {
+    let mut char_counts = CharCounts::default();
+    let bytes = data.as_bytes();
+
+    for i in bytes.iter().take(window_size) {
+        char_counts.add(*i);
+    }
+
+    // check current window for answer or continue with
+    // slide window by adding front value and removing back value
+    for i in window_size..bytes.len() {
+        if char_counts.0.iter().len() == window_size {
+            return Ok(i);
+        }
+
+        char_counts.add(bytes[i]);
+        char_counts.remove(bytes[i - window_size])?;
+    }
+
+    // the last window is only checked once every character has been read
+    match char_counts.0.iter().len() == window_size {
+        true => Ok(bytes.len()),
+        false => Err("Answer not found"),
+    }
+}
+
+#[derive(Default)]
+struct CharCounts(HashMap<u8, usize>);
+
+impl CharCounts {
+    /// add a new key into the HashMap with count 1
+    /// or increment an existing key by 1
+    fn add(&mut self, c: u8) {
+        self.0
+            .entry(c)
+            .and_modify(|counter| *counter += 1)
+            .or_insert(1);
+    }
+
+    /// remove a key with a count of 1
+    /// or decrement a count higher than 1
+    fn remove(&mut self, c: u8) -> Result<(), &'static str> {
+        let count = match self.0.get(&c) {
+            Some(count) => count,
+            None => return Err("Key does not exist"),
+        };
+
+        match *count == 1 {
+            true => self.0.remove(&c),
+            false => self.0.insert(c, count - 1),
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse_input, Answer, Solver};
+
+    use crate::{CharCounts, Day6};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day6>(EXAMPLE).unwrap();
+        assert_eq!(Day6::part_one(&input), Answer::from(7));
+        assert_eq!(Day6::part_two(&input), Answer::from(19));
+    }
+
+    #[test]
+    #[allow(
+        unused_must_use,
+        clippy::unnecessary_cast,
+        clippy::bool_assert_comparison
+    )]
+    fn char_counts() {
+        let mut char_counts = CharCounts::default();
+
+        // test add fn
+        char_counts.add(2);
+        assert_eq!(*char_counts.0.get(&2).unwrap(), 1 as usize);
+        char_counts.add(2);
+        char_counts.add(1);
+        assert_eq!(*char_counts.0.get(&1).unwrap(), 1 as usize);
+        assert_eq!(*char_counts.0.get(&2).unwrap(), 2 as usize);
+
+        // test remove fn
+        char_counts.remove(2);
+        assert_eq!(*char_counts.0.get(&2).unwrap(), 1 as usize);
+        char_counts.remove(2);
+        assert_eq!(char_counts.0.get(&2), None);
+        assert_eq!(char_counts.remove(2).is_err(), true);
+        char_counts.remove(1);
+        assert_eq!(char_counts.0.get(&1), None);
+    }
+
+    #[test]
+    fn test_no_marker() {
+        for data in ["", "x", "abc def"] {
+            let input = parse_input::<Day6>(data).unwrap();
+            assert_eq!(Day6::part_two(&input), Answer::Unsolved);
+        }
+        let input = parse_input::<Day6>("aabcd").unwrap();
+        assert_eq!(Day6::part_one(&input), Answer::from(5));
+        assert_eq!(
+            Day6::part_one(&parse_input::<Day6>("x").unwrap()),
+            Answer::Unsolved
+        );
+    }
+}
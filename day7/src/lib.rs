@@ -0,0 +1,361 @@
+use aoc_common::{input_file, parse_token, Answer, ParseError, Solver};
+use std::collections::{HashMap, HashSet};
+
+pub const FILE_NAME: &str = input_file!();
+const ROOT_NODE_NAME: &str = "root";
+const ROOT_NODE_INDEX: usize = 0;
+
+// criterias
+const PART_ONE_MAX_DIRECTORY_SIZE: usize = 100000;
+const PART_TWO_DISK_SPACE: usize = 70000000;
+const PART_TWO_SPACE_NEEDED: usize = 30000000;
+
+// terminal output forms
+const LINE_FORM: &str = "`$ <command>`, `dir <name>` or `<size> <name>`";
+const COMMAND_FORM: &str = "`ls` or `cd <directory>`";
+
+// terminal command constants
+const COMMAND_TOKEN: &str = "$";
+const ROOT_TOKEN: &str = "/";
+const PARENT_TOKEN: &str = "..";
+const COMMAND_TYPE_INDEX: usize = 1;
+const CHANGE_DIRECTORY_NAME_INDEX: usize = 2;
+
+// directory constants
+const DIRECTORY_TOKEN: &str = "dir";
+const DIRECTORY_NAME_INDEX: usize = 1;
+
+// file constants
+const FILE_SIZE_INDEX: usize = 0;
+const FILE_NAME_INDEX: usize = 1;
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    /// The total size of every directory. The root directory is stored at ROOT_NODE_INDEX.
+    type Input = Vec<usize>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        get_total_sizes(data)
+    }
+
+    fn part_one(total_sizes: &Self::Input) -> Answer {
+        let mut part_one_answer = 0;
+        for node_size in total_sizes {
+            if *node_size < PART_ONE_MAX_DIRECTORY_SIZE {
+                part_one_answer += node_size;
+            }
+        }
+        part_one_answer.into()
+    }
+
+    fn part_two(total_sizes: &Self::Input) -> Answer {
+        let root_size = total_sizes[ROOT_NODE_INDEX];
+        let space_needed =
+            PART_TWO_SPACE_NEEDED.saturating_sub(PART_TWO_DISK_SPACE.saturating_sub(root_size));
+        match total_sizes
+            .iter()
+            .filter(|node_size| **node_size > space_needed)
+            .min()
+        {
+            Some(part_two_answer) => (*part_two_answer).into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+/// This large function should be split but it is difficult due to lifetimes. It currently completes two different purposes.
+/// 1. Parse input to build a file system of directories aka nodes. The nodes are stored in an array at
+///    specfic indexes. The indexes are used to relate parent nodes to children nodes.
+/// 2. Find the total size of each directory by using depth first search to traverse the entire file system.
+///    Total size is the size of all files stored directly in a directory and all of its children directories.
+fn get_total_sizes(data: &str) -> Result<Vec<usize>, ParseError> {
+    let mut nodes: Vec<Option<Node>> = vec![];
+    let mut node_indexes: HashMap<NodeID, usize> = HashMap::new();
+
+    // setup and start at root node to build remainder of file system
+    node_indexes.insert(NodeID::new(ROOT_NODE_NAME, 0), 0);
+    let root_node = Node::new(ROOT_NODE_NAME, 0);
+    nodes.push(Some(root_node));
+    let mut current_node = nodes[ROOT_NODE_INDEX].take().unwrap();
+
+    for (line_index, line) in data.lines().enumerate() {
+        let input = parse_line(line_index, line)?;
+
+        match input {
+            Input::Command(command) => {
+                if command == Command::List {
+                    continue;
+                } else if Command::get_directory(command) == ROOT_TOKEN {
+                    // change current node to root node
+                    let current_node_id = NodeID::new(current_node.name, current_node.parent_index);
+                    let current_node_index = *node_indexes.get(&current_node_id).unwrap();
+                    nodes[current_node_index] = Some(current_node);
+                    current_node = nodes[ROOT_NODE_INDEX].take().unwrap();
+                } else if Command::get_directory(command) == PARENT_TOKEN {
+                    // change current node to parent of current node
+                    let parent_node_index = current_node.parent_index;
+                    let current_node_id = NodeID::new(current_node.name, current_node.parent_index);
+                    let current_node_index = *node_indexes.get(&current_node_id).unwrap();
+                    if current_node_index == ROOT_NODE_INDEX {
+                        return Err(ParseError::new(
+                            line_index,
+                            line,
+                            Command::get_directory(command),
+                            "a directory to enter, as the root directory has no parent",
+                        ));
+                    }
+                    nodes[current_node_index] = Some(current_node);
+                    current_node = nodes[parent_node_index].take().unwrap();
+                } else {
+                    // change current node to node specified
+                    let new_node_name = Command::get_directory(command);
+
+                    let current_node_id = NodeID::new(current_node.name, current_node.parent_index);
+                    let current_node_index = *node_indexes.get(&current_node_id).unwrap();
+                    let new_node_id = NodeID::new(new_node_name, current_node_index);
+
+                    // only directories listed by ls are part of the file system
+                    let node_index = match node_indexes.get(&new_node_id) {
+                        Some(node_index) => *node_index,
+                        None => {
+                            return Err(ParseError::new(
+                                line_index,
+                                line,
+                                new_node_name,
+                                "a directory listed in the current directory",
+                            ))
+                        }
+                    };
+                    nodes[current_node_index] = Some(current_node);
+                    current_node = nodes[node_index].take().unwrap();
+                }
+            }
+            Input::File(file) => {
+                current_node.files.insert(file);
+            }
+            Input::Directory(name) => {
+                let current_node_id = NodeID::new(current_node.name, current_node.parent_index);
+                let current_node_index = *node_indexes.get(&current_node_id).unwrap();
+                let new_node_id = NodeID::new(name, current_node_index);
+
+                // create node if it does not already exist
+                match node_indexes.get(&new_node_id) {
+                    Some(_) => continue,
+                    None => {
+                        node_indexes.insert(new_node_id, nodes.len());
+                        let new_node = Node::new(name, current_node_index);
+                        current_node.children_indexes.insert(nodes.len());
+                        nodes.push(Some(new_node));
+                    }
+                }
+            }
+        }
+    }
+
+    // return ownership of current_node back to node tracker
+    let current_node_id = NodeID::new(current_node.name, current_node.parent_index);
+    let current_node_index = *node_indexes.get(&current_node_id).unwrap();
+    nodes[current_node_index] = Some(current_node);
+
+    // Use DFS to traverse file system and find the total size for each directory
+    let mut visited: HashSet<NodeID> = HashSet::new();
+    let mut current_node = nodes[ROOT_NODE_INDEX].take().unwrap();
+    let mut current_node_index = ROOT_NODE_INDEX;
+    let mut stack = vec![current_node_index];
+    while !stack.is_empty() {
+        let current_node_id = NodeID::new(current_node.name, current_node.parent_index);
+
+        if !visited.contains(&current_node_id) {
+            stack.push(current_node_index);
+            for child_index in &current_node.children_indexes {
+                stack.push(*child_index);
+            }
+            visited.insert(current_node_id);
+        } else {
+            let mut total_size = 0;
+            for file in &current_node.files {
+                total_size += file.size;
+            }
+            for child_index in &current_node.children_indexes {
+                let child_total_size = nodes[*child_index].as_ref().unwrap().total_size.unwrap();
+                total_size += child_total_size;
+            }
+            current_node.total_size = Some(total_size);
+        }
+
+        nodes[current_node_index] = Some(current_node);
+        current_node_index = stack.pop().unwrap();
+        current_node = nodes[current_node_index].take().unwrap();
+    }
+
+    // return ownership of current_node back to node tracker
+    let current_node_id = NodeID::new(current_node.name, current_node.parent_index);
+    let current_node_index = *node_indexes.get(&current_node_id).unwrap();
+    nodes[current_node_index] = Some(current_node);
+
+    Ok(nodes
+        .iter()
+        .map(|node| node.as_ref().unwrap().total_size.unwrap())
+        .collect())
+}
+
+fn parse_line(line_index: usize, line: &str) -> Result<Input<'_>, ParseError> {
+    let tokens: Vec<&str> = line.split(' ').collect();
+
+    if tokens[0] == COMMAND_TOKEN {
+        Ok(Input::Command(parse_command(line_index, line, &tokens)?))
+    } else if tokens[0] == DIRECTORY_TOKEN {
+        Ok(Input::Directory(get_token(
+            line_index,
+            line,
+            &tokens,
+            DIRECTORY_NAME_INDEX,
+            "a directory name",
+        )?))
+    } else {
+        let file = File::new(
+            parse_token(line_index, line, tokens[FILE_SIZE_INDEX], LINE_FORM)?,
+            get_token(line_index, line, &tokens, FILE_NAME_INDEX, "a file name")?,
+        );
+        Ok(Input::File(file))
+    }
+}
+
+fn parse_command<'a>(
+    line_index: usize,
+    line: &'a str,
+    tokens: &[&'a str],
+) -> Result<Command<'a>, ParseError> {
+    let command_type = get_token(line_index, line, tokens, COMMAND_TYPE_INDEX, COMMAND_FORM)?;
+
+    match command_type {
+        "ls" => Ok(Command::List),
+        "cd" => Ok(Command::ChangeDirectory(get_token(
+            line_index,
+            line,
+            tokens,
+            CHANGE_DIRECTORY_NAME_INDEX,
+            "a directory name",
+        )?)),
+        _ => Err(ParseError::new(
+            line_index,
+            line,
+            command_type,
+            COMMAND_FORM,
+        )),
+    }
+}
+
+/// Returns the token at the passed index or an error if the line ends before it
+fn get_token<'a>(
+    line_index: usize,
+    line: &str,
+    tokens: &[&'a str],
+    index: usize,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    match tokens.get(index) {
+        Some(token) => Ok(token),
+        None => Err(ParseError::end_of_line(line_index, line, expected)),
+    }
+}
+
+enum Input<'a> {
+    Command(Command<'a>),
+    File(File<'a>),
+    Directory(&'a str),
+}
+
+// TODO remove Clone and Copy
+#[derive(Clone, Copy, PartialEq)]
+enum Command<'a> {
+    ChangeDirectory(&'a str),
+    List,
+}
+
+impl Command<'_> {
+    fn get_directory(command: Command<'_>) -> &str {
+        match command {
+            Command::ChangeDirectory(name) => name,
+            Command::List => panic!("Cannot get directory name for Command::List"),
+        }
+    }
+}
+
+#[derive(Eq, Hash, PartialEq)]
+struct File<'a> {
+    size: usize,
+    name: &'a str,
+}
+
+impl File<'_> {
+    fn new(size: usize, name: &str) -> File<'_> {
+        File { name, size }
+    }
+}
+
+/// Uniquely identifies a directory. May be used to quicly find a directory in the file system.
+#[derive(Hash, Eq, PartialEq)]
+struct NodeID<'a> {
+    name: &'a str,
+    parent_index: usize,
+}
+
+impl NodeID<'_> {
+    fn new(name: &str, parent_index: usize) -> NodeID<'_> {
+        NodeID { name, parent_index }
+    }
+}
+
+/// Represents a directory. The parent and children nodes are stored in an external array.
+/// Total size is the size of all files stored directly in this directory and all children directories.
+struct Node<'a> {
+    name: &'a str,
+    parent_index: usize,
+    children_indexes: HashSet<usize>,
+    files: HashSet<File<'a>>,
+    total_size: Option<usize>,
+}
+
+impl Node<'_> {
+    fn new(name: &str, parent_index: usize) -> Node<'_> {
+        Node {
+            name,
+            parent_index,
+            children_indexes: HashSet::new(),
+            files: HashSet::new(),
+            total_size: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{parse_input, Answer, Solver};
+
+    use crate::Day7;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day7>(EXAMPLE).unwrap();
+        assert_eq!(Day7::part_one(&input), Answer::from(95437));
+        assert_eq!(Day7::part_two(&input), Answer::from(24933642));
+    }
+
+    #[test]
+    fn test_invalid_directories() {
+        let error = parse_input::<Day7>("$ cd /\n$ cd ..").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 6));
+
+        let error = parse_input::<Day7>("$ cd /\n$ ls\ndir a\n$ cd b")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (4, 6, "b"));
+
+        let input = parse_input::<Day7>("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n1 x").unwrap();
+        assert_eq!(Day7::part_two(&input), Answer::from(1));
+    }
+}
@@ -0,0 +1,244 @@
+use aoc_common::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    input_file, Answer, ParseError, Solver,
+};
+
+pub const FILE_NAME: &str = input_file!();
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Input = Trees;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Trees::new(data)
+    }
+
+    fn part_one(trees: &Self::Input) -> Answer {
+        trees
+            .heights
+            .points()
+            .filter(|point| trees.is_visible(point))
+            .count()
+            .into()
+    }
+
+    fn part_two(trees: &Self::Input) -> Answer {
+        trees
+            .heights
+            .points()
+            .map(|point| trees.get_visibility(&point))
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+pub struct Trees {
+    heights: Grid<u32>,
+}
+
+impl Trees {
+    fn new(data: &str) -> Result<Trees, ParseError> {
+        let heights = Grid::parse(data, "a tree height from 0 to 9", |c| c.to_digit(10))?;
+
+        Ok(Trees { heights })
+    }
+
+    /// A tree is visible if every tree between it and an edge is shorter
+    fn is_visible(&self, point: &Point) -> bool {
+        let height = self.heights[*point];
+
+        Direction::ALL.iter().any(|direction| {
+            self.heights
+                .ray(*point, *direction)
+                .all(|(_, tree_height)| *tree_height < height)
+        })
+    }
+
+    /// The product of the number of trees that can be seen in every direction. Trees can
+    /// be seen up to and including the first tree as tall or taller than this tree.
+    fn get_visibility(&self, point: &Point) -> usize {
+        let height = self.heights[*point];
+
+        Direction::ALL
+            .iter()
+            .map(|direction| {
+                let mut visibility = 0;
+                for (_, tree_height) in self.heights.ray(*point, *direction) {
+                    visibility += 1;
+                    if *tree_height >= height {
+                        break;
+                    }
+                }
+                visibility
+            })
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{geometry::Point, parse_input, Answer, Solver};
+
+    use crate::{Day8, Trees};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day8>(EXAMPLE).unwrap();
+        assert_eq!(Day8::part_one(&input), Answer::from(21));
+        assert_eq!(Day8::part_two(&input), Answer::from(8));
+    }
+
+    #[test]
+    fn test_trees_new() {
+        /*
+        123
+        456
+        789
+        */
+        let data = "123\n456\n789\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.heights[Point(0, 0)], 1);
+        assert_eq!(trees.heights[Point(2, 0)], 3);
+        assert_eq!(trees.heights[Point(0, 2)], 7);
+        assert_eq!(trees.heights[Point(2, 2)], 9);
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn test_trees_is_visible() {
+        /*
+        33333
+        12122
+        33333
+        */
+        let data = "33333\n12122\n33333\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.is_visible(&Point(0, 1)), true);
+        assert_eq!(trees.is_visible(&Point(1, 1)), true);
+        assert_eq!(trees.is_visible(&Point(2, 1)), false);
+        assert_eq!(trees.is_visible(&Point(3, 1)), false);
+        assert_eq!(trees.is_visible(&Point(4, 1)), true);
+
+        /*
+        33333
+        22121
+        33333
+        */
+        let data = "33333\n22121\n33333\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.is_visible(&Point(0, 1)), true);
+        assert_eq!(trees.is_visible(&Point(1, 1)), false);
+        assert_eq!(trees.is_visible(&Point(2, 1)), false);
+        assert_eq!(trees.is_visible(&Point(3, 1)), true);
+        assert_eq!(trees.is_visible(&Point(3, 1)), true);
+
+        /*
+        313
+        323
+        313
+        323
+        323
+        */
+        let data = "313\n323\n313\n323\n323\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.is_visible(&Point(1, 0)), true);
+        assert_eq!(trees.is_visible(&Point(1, 1)), true);
+        assert_eq!(trees.is_visible(&Point(1, 2)), false);
+        assert_eq!(trees.is_visible(&Point(1, 3)), false);
+        assert_eq!(trees.is_visible(&Point(1, 4)), true);
+
+        /*
+        323
+        323
+        313
+        323
+        313
+        */
+        let data = "323\n323\n313\n323\n313\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.is_visible(&Point(1, 0)), true);
+        assert_eq!(trees.is_visible(&Point(1, 1)), false);
+        assert_eq!(trees.is_visible(&Point(1, 2)), false);
+        assert_eq!(trees.is_visible(&Point(1, 3)), true);
+        assert_eq!(trees.is_visible(&Point(1, 3)), true);
+    }
+
+    #[test]
+    fn test_trees_visibility() {
+        /*
+        111111
+        121122
+        111111
+        */
+        let data = "111111\n121122\n111111\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.get_visibility(&Point(0, 1)), 0);
+        assert_eq!(trees.get_visibility(&Point(1, 1)), 3);
+        assert_eq!(trees.get_visibility(&Point(2, 1)), 1);
+        assert_eq!(trees.get_visibility(&Point(3, 1)), 1);
+        assert_eq!(trees.get_visibility(&Point(4, 1)), 3);
+        assert_eq!(trees.get_visibility(&Point(5, 1)), 0);
+
+        /*
+        111111
+        221121
+        111111
+        */
+        let data = "111111\n221121\n111111\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.get_visibility(&Point(0, 1)), 0);
+        assert_eq!(trees.get_visibility(&Point(1, 1)), 3);
+        assert_eq!(trees.get_visibility(&Point(2, 1)), 1);
+        assert_eq!(trees.get_visibility(&Point(3, 1)), 1);
+        assert_eq!(trees.get_visibility(&Point(4, 1)), 3);
+        assert_eq!(trees.get_visibility(&Point(5, 1)), 0);
+
+        /*
+        111
+        121
+        111
+        111
+        121
+        121
+        */
+        let data = "111\n121\n111\n111\n121\n121\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.get_visibility(&Point(1, 0)), 0);
+        assert_eq!(trees.get_visibility(&Point(1, 1)), 3);
+        assert_eq!(trees.get_visibility(&Point(1, 2)), 1);
+        assert_eq!(trees.get_visibility(&Point(1, 3)), 1);
+        assert_eq!(trees.get_visibility(&Point(1, 4)), 3);
+        assert_eq!(trees.get_visibility(&Point(1, 5)), 0);
+
+        /*
+        121
+        121
+        111
+        111
+        121
+        111
+        */
+        let data = "121\n121\n111\n111\n121\n111\n";
+
+        let trees = Trees::new(data).unwrap();
+        assert_eq!(trees.get_visibility(&Point(1, 0)), 0);
+        assert_eq!(trees.get_visibility(&Point(1, 1)), 3);
+        assert_eq!(trees.get_visibility(&Point(1, 2)), 1);
+        assert_eq!(trees.get_visibility(&Point(1, 3)), 1);
+        assert_eq!(trees.get_visibility(&Point(1, 4)), 3);
+        assert_eq!(trees.get_visibility(&Point(1, 5)), 0);
+    }
+}
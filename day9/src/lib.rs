@@ -0,0 +1,299 @@
+use aoc_common::{
+    geometry::{Direction, Direction8, Point},
+    input_file, parse_token, Answer, ParseError, Solver,
+};
+use std::collections::HashSet;
+
+// file constants
+pub const FILE_NAME: &str = input_file!();
+const DIRECTION_INDEX: usize = 0;
+const DIRECTION_MULTIPLIER_INDEX: usize = 1;
+
+// point constants
+const X_DISTANCE: i32 = 1;
+const Y_DISTANCE: i32 = 1;
+
+// head and node constants
+const START_X_INDEX: i32 = 0;
+const START_Y_INDEX: i32 = 0;
+const PART_ONE_NODE_COUNT: usize = 1;
+const PART_TWO_NODE_COUNT: usize = 8;
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    /// The direction and number of steps of every head motion
+    type Input = Vec<(Direction, usize)>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines()
+            .enumerate()
+            .map(|(i, line)| parse_line(i, line))
+            .collect()
+    }
+
+    fn part_one(motions: &Self::Input) -> Answer {
+        let mut head = Head::new(PART_ONE_NODE_COUNT);
+
+        motions.iter().for_each(|(direction, multipler)| {
+            for _ in 0..*multipler {
+                head.travel(direction);
+
+                match within_grid(
+                    (&head.position, &head.next.position),
+                    (X_DISTANCE, Y_DISTANCE),
+                ) {
+                    true => continue,
+                    false => {
+                        head.next.travel(&head.position);
+                        head.next.update_history();
+                    }
+                }
+            }
+        });
+
+        let last_node = head.next;
+
+        last_node.unique_position_count.into()
+    }
+
+    fn part_two(motions: &Self::Input) -> Answer {
+        let mut head = Head::new(PART_TWO_NODE_COUNT);
+
+        motions.iter().for_each(|(direction, multipler)| {
+            for _ in 0..*multipler {
+                head.travel(direction);
+
+                match within_grid(
+                    (&head.position, &head.next.position),
+                    (X_DISTANCE, Y_DISTANCE),
+                ) {
+                    true => continue,
+                    false => {
+                        head.next.travel(&head.position);
+                        head.next.update_history();
+                    }
+                }
+
+                let mut next_node = &mut head.next;
+
+                while next_node.next.is_some() {
+                    if !within_grid(
+                        (
+                            &next_node.position,
+                            &next_node.next.as_ref().unwrap().position,
+                        ),
+                        (X_DISTANCE, Y_DISTANCE),
+                    ) {
+                        next_node.next.as_mut().unwrap().travel(&next_node.position);
+                        next_node.next.as_mut().unwrap().update_history();
+                    }
+
+                    next_node = next_node.next.as_mut().unwrap();
+                }
+            }
+        });
+
+        let mut last_node = &mut head.next;
+        while last_node.next.is_some() {
+            last_node = last_node.next.as_mut().unwrap();
+        }
+
+        last_node.unique_position_count.into()
+    }
+}
+
+fn parse_line(line_index: usize, line: &str) -> Result<(Direction, usize), ParseError> {
+    let tokens: Vec<&str> = line.split(' ').collect();
+
+    let direction = parse_token(
+        line_index,
+        line,
+        tokens[DIRECTION_INDEX],
+        "a direction `U`, `D`, `R` or `L`",
+    )?;
+    let multiplier = match tokens.get(DIRECTION_MULTIPLIER_INDEX) {
+        Some(token) => parse_token(line_index, line, token, "a number of steps")?,
+        None => {
+            return Err(ParseError::end_of_line(
+                line_index,
+                line,
+                "a number of steps",
+            ))
+        }
+    };
+
+    Ok((direction, multiplier))
+}
+
+/// returns true if the two points are within the grid created by the passed distances.
+/// The distances will be used for both x and y directions separately. E.g. Points ((0,0), (1, -2)))
+/// with (1, 2) distances will return true since x moved <= x distance and y moved <= y distance
+fn within_grid(points: (&Point, &Point), distances: (i32, i32)) -> bool {
+    let difference = *points.0 - *points.1;
+    difference.0.abs() <= distances.0 && difference.1.abs() <= distances.1
+}
+
+fn adjacent(points: (&Point, &Point)) -> bool {
+    points.0.manhattan_distance(*points.1) == 1
+}
+
+struct Head {
+    position: Point,
+    next: Node,
+}
+
+impl Head {
+    fn new(node_count: usize) -> Head {
+        Head {
+            position: Point(START_X_INDEX, START_Y_INDEX),
+            next: Node::new(node_count),
+        }
+    }
+
+    fn travel(&mut self, direction: &Direction) {
+        self.position = self.position.step(*direction);
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct Node {
+    position: Point,
+    history: HashSet<Point>,
+    unique_position_count: usize,
+    next: Option<Box<Node>>,
+}
+
+impl Node {
+    fn new(node_count_remainder: usize) -> Node {
+        let mut history = HashSet::new();
+        history.insert(Point(START_X_INDEX, START_Y_INDEX));
+
+        let mut next_node = None;
+        if node_count_remainder > 0 {
+            next_node = Some(Box::new(Node::new(node_count_remainder - 1)));
+        }
+
+        Node {
+            position: Point(START_X_INDEX, START_Y_INDEX),
+            history,
+            unique_position_count: 1,
+            next: next_node,
+        }
+    }
+
+    fn update_history(&mut self) {
+        match self.history.get(&self.position) {
+            Some(_) => (),
+            None => {
+                self.unique_position_count += 1;
+                self.history.insert(self.position);
+            }
+        };
+    }
+
+    /// Travel toward the passed position. The travel has three priority levels.
+    /// First priority: Travel up, down, left or right to get adjacent to passed point
+    /// Second priority: Travel 1 space diagonally to get adjacent to passed point
+    /// Third priority: Travel 1 space diagonally to get diagonal to passed point
+    fn travel(&mut self, toward_position: &Point) {
+        // First priority: Travel up, down, left or right to get adjacent to passed point
+        for direction in Direction::ALL {
+            let new_position = self.position.step(direction);
+
+            match adjacent((&new_position, toward_position)) {
+                true => {
+                    self.position = new_position;
+                    return;
+                }
+                false => continue,
+            }
+        }
+
+        // Second priority: Travel 1 space diagonally to get adjacent to passed point
+        for diagonal_direction in Direction8::DIAGONALS {
+            let new_position = self.position.step8(diagonal_direction);
+
+            match adjacent((&new_position, toward_position)) {
+                true => {
+                    self.position = new_position;
+                    return;
+                }
+                false => continue,
+            }
+        }
+
+        // Third priority: Travel 1 space diagonally to get diagonal to passed point
+        for diagonal_direction in Direction8::DIAGONALS {
+            let new_position = self.position.step8(diagonal_direction);
+
+            match within_grid((&new_position, toward_position), (X_DISTANCE, Y_DISTANCE)) {
+                true => {
+                    self.position = new_position;
+                    return;
+                }
+                false => continue,
+            }
+        }
+
+        panic!("No direction or diagonal direction travel will get near connected position");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{geometry::Point, parse_input, Answer, Solver};
+
+    use crate::{adjacent, within_grid, Day9};
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_example() {
+        let input = parse_input::<Day9>(EXAMPLE).unwrap();
+        assert_eq!(Day9::part_one(&input), Answer::from(13));
+        assert_eq!(Day9::part_two(&input), Answer::from(1));
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn points_within() {
+        assert_eq!(within_grid((&Point(0, 0), &Point(0, 0)), (0, 0)), true);
+
+        assert_eq!(within_grid((&Point(0, 0), &Point(2, 1)), (3, 1)), true);
+        assert_eq!(within_grid((&Point(0, 0), &Point(2, 1)), (2, 2)), true);
+        assert_eq!(within_grid((&Point(0, 0), &Point(2, 1)), (2, 1)), true);
+        assert_eq!(within_grid((&Point(0, 0), &Point(2, 1)), (1, 1)), false);
+        assert_eq!(within_grid((&Point(0, 0), &Point(2, 1)), (2, 0)), false);
+
+        assert_eq!(within_grid((&Point(1, 2), &Point(0, 0)), (1, 3)), true);
+        assert_eq!(within_grid((&Point(1, 2), &Point(0, 0)), (2, 2)), true);
+        assert_eq!(within_grid((&Point(1, 2), &Point(0, 0)), (1, 2)), true);
+        assert_eq!(within_grid((&Point(1, 2), &Point(0, 0)), (1, 1)), false);
+        assert_eq!(within_grid((&Point(1, 2), &Point(0, 0)), (0, 2)), false);
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn points_connected() {
+        assert_eq!(adjacent((&Point(0, 0), &Point(0, 0))), false);
+
+        assert_eq!(adjacent((&Point(0, 0), &Point(1, 0))), true);
+        assert_eq!(adjacent((&Point(0, 0), &Point(-1, 0))), true);
+        assert_eq!(adjacent((&Point(0, 0), &Point(0, 1))), true);
+        assert_eq!(adjacent((&Point(0, 0), &Point(0, -1))), true);
+        assert_eq!(adjacent((&Point(1, 0), &Point(0, 0))), true);
+        assert_eq!(adjacent((&Point(-1, 0), &Point(0, 0))), true);
+        assert_eq!(adjacent((&Point(0, 1), &Point(0, 0))), true);
+        assert_eq!(adjacent((&Point(0, -1), &Point(0, 0))), true);
+
+        assert_eq!(adjacent((&Point(0, 0), &Point(1, 1))), false);
+        assert_eq!(adjacent((&Point(0, 0), &Point(-1, 1))), false);
+        assert_eq!(adjacent((&Point(0, 0), &Point(-1, -1))), false);
+        assert_eq!(adjacent((&Point(0, 0), &Point(1, -1))), false);
+        assert_eq!(adjacent((&Point(1, 1), &Point(0, 0))), false);
+        assert_eq!(adjacent((&Point(-1, 1), &Point(0, 0))), false);
+        assert_eq!(adjacent((&Point(-1, -1), &Point(0, 0))), false);
+        assert_eq!(adjacent((&Point(1, -1), &Point(0, 0))), false);
+    }
+}